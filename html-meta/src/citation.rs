@@ -2,22 +2,46 @@
 
 use crate::{
     dom::Dom,
-    error::{MetaError, MetaResult},
+    entry::EntryBuilder,
+    error::{Errors, MetaError, MetaResult},
+    feed::Feed,
+    figure::Figure,
     meta::{
+        cff::CitationFile,
+        dublin_core::DublinCoreMetadata,
         generic::GenericMetadata,
+        highwire::HighwireMetadata,
         ogp::{OgpArticle, OgpAudio, OgpBook, OgpImage, OgpMetadata, OgpVideo},
-        value::{into_qualified, DateIso8601, Name, Title},
+        value::{Name, PageRange, PartialDate},
     },
-    priority::PriorityData,
     query::HtmlQueryReport,
+    site::{
+        archive::ArchivalRecord,
+        artwork::Artwork,
+        arxiv::ArxivPaper,
+        blog::{Blog, BlogSeries},
+        book::CatalogueBook,
+        crates::{CratePage, RustCrate},
+        dataset::{CkanDataset, ZenodoRecord},
+        docs::DocsChapter,
+        forum::{Forum, ForumPost, ForumThread},
+        issue::{Issue, IssueKind},
+        mailing_list::MailingListMessage,
+        reference::ReferenceEntry,
+        report::WorkingPaper,
+        repository::{self, SoftwareRepository},
+        social::{SocialPost, SocialThread},
+        standard::Standard,
+        video::Video,
+        wiki::WikiArticle,
+    },
 };
 use hayagriva::{io::to_yaml_str, types::EntryType, Entry};
 use isbn2::Isbn;
-use unic_langid::LanguageIdentifier;
 use url::Url;
 use wasm_bindgen::prelude::wasm_bindgen;
 
-const PLACEHOLDER: &'static str = "placeholder";
+mod schema_org;
 
 #[wasm_bindgen]
 #[derive(Debug, Clone, PartialEq)]
@@ -29,15 +53,39 @@ pub struct Citation {
 impl Citation {
     #[wasm_bindgen(constructor)]
     pub fn new(dom: Dom) -> MetaResult<Citation> {
-        let citation = CitationBuilder::new(dom)
-            // .with_html_query_report()?
-            .with_generic_metadata()?
-            .with_ogp_metadata()?
-            .build();
+        let citation = CitationBuilder::from_dom(dom)?.build();
 
         Ok(citation)
     }
 
+    /// Cites an image or a figure of the page, e.g. "figure.lead", with the page as parent.
+    pub fn for_element(dom: Dom, selector: &str) -> MetaResult<Citation> {
+        let Figure {
+            caption,
+            credit,
+            alt,
+            image,
+        } = Figure::select(&dom, selector)?;
+        let page = CitationBuilder::from_dom(dom)?;
+        let mut entry = EntryBuilder::new(EntryType::Artwork);
+
+        entry.title.first = caption.or(alt).map(Into::into);
+        entry.authors.first = credit.map(|credit| vec![credit]);
+        entry.url.first = image;
+        entry.parent = Some(Box::new(page.entry));
+
+        Ok(Entry::from(entry).into())
+    }
+
+    /// Cites the episode of a podcast feed whose web page or audio file is at the given url.
+    pub fn from_feed(feed: &Feed, url: &str) -> MetaResult<Citation> {
+        let url = Url::parse(url)?;
+        let episode = feed.episode(&url).ok_or(MetaError::EpisodeNotFound)?;
+        let entry = Entry::from(feed.entry(episode));
+
+        Ok(entry.into())
+    }
+
     pub fn to_yaml_str(&self) -> MetaResult<String> {
         to_yaml_str([&self.entry]).ok_or(MetaError::YamlParse)
     }
@@ -51,54 +99,65 @@ impl From<Entry> for Citation {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CitationBuilder {
-    entry_type: EntryType,
     dom: Dom,
-    title: PriorityData<Title>,
-    authors: PriorityData<Vec<Name>>,
-    date: PriorityData<DateIso8601>,
-    editors: PriorityData<Vec<Name>>,
-    // affiliated_persons: Vec<(Vec<Person>, PersonRole)>,
-    publisher: PriorityData<String>,
-    location: PriorityData<String>,
-    organization: PriorityData<String>,
-    url: PriorityData<Url>,
-    serial_number: PriorityData<String>,
-    isbn: PriorityData<Isbn>,
-    language: PriorityData<LanguageIdentifier>,
-    note: PriorityData<String>,
+    entry: EntryBuilder,
 }
 
 impl CitationBuilder {
     pub fn new(dom: Dom) -> Self {
-        let url = PriorityData {
-            first: Some(dom.url().clone()),
-            second: None,
-            third: None,
-        };
+        let mut entry = EntryBuilder::new(EntryType::Web);
+        entry.url.first = Some(dom.url().clone());
 
-        Self {
-            dom,
-            entry_type: EntryType::Web,
-            title: Default::default(),
-            authors: Default::default(),
-            date: Default::default(),
-            editors: Default::default(),
-            publisher: Default::default(),
-            location: Default::default(),
-            organization: Default::default(),
-            url,
-            serial_number: Default::default(),
-            isbn: Default::default(),
-            language: Default::default(),
-            note: Default::default(),
-        }
+        Self { dom, entry }
+    }
+
+    /// Collects the data of all metadata formats and site adapters of the page.
+    pub fn from_dom(dom: Dom) -> MetaResult<Self> {
+        let builder = Self::new(dom)
+            .with_html_query_report()?
+            .with_generic_metadata()?
+            // a newspaper detected later on replaces the blog and its owner as parent
+            .with_blog()?
+            .with_ogp_metadata()?
+            .with_working_paper()?
+            .with_data_repository()?
+            .with_software_repository()?
+            .with_rust_crate()?
+            .with_docs_chapter()?
+            .with_catalogue_book()?
+            .with_issue()?
+            .with_standard()?
+            .with_artwork()?
+            .with_archival_record()?
+            .with_wiki_article()?
+            .with_video()?
+            .with_social_post()?
+            .with_forum_thread()?
+            .with_mailing_list_message()?
+            .with_dublin_core_metadata()?
+            .with_schema_metadata()?
+            .with_highwire_metadata()?
+            .with_reference_entry()?
+            .with_arxiv_paper()?;
+
+        Ok(builder)
     }
 
     pub fn with_html_query_report(mut self) -> MetaResult<Self> {
-        let HtmlQueryReport { title } = self.dom.html_query_report()?;
+        let HtmlQueryReport { title, authors } = self.dom.html_query_report()?;
 
         if let Some(title) = title {
-            self.title.third = Some(title);
+            self.entry.title.third = Some(title);
+        }
+
+        // only the names of the most certain bylines, as the others are often unrelated
+        if let Some(confidence) = authors.first().map(|author| author.confidence) {
+            let authors = authors
+                .into_iter()
+                .take_while(|author| author.confidence == confidence)
+                .map(|author| author.name)
+                .collect();
+            self.entry.authors.third = Some(authors);
         }
 
         Ok(self)
@@ -115,21 +174,36 @@ impl CitationBuilder {
         } = self.dom.generic_metadata()?;
 
         if let Some(title) = title {
-            self.title.second = Some(title);
+            self.entry.title.second = Some(title);
         }
 
         if let Some(language) = language {
-            self.language.second = Some(language);
+            self.entry.language.second = Some(language);
         }
 
         if let Some(author) = author {
-            self.authors.second = Some(vec![author]);
+            self.entry.authors.second = Some(vec![author]);
         }
 
         Ok(self)
     }
 
     pub fn with_ogp_metadata(mut self) -> MetaResult<Self> {
+        let metadata = self.dom.ogp_metadata()?;
+        // a blog detected before is kept as parent, even if it files its posts like a newspaper
+        let is_blog = self
+            .entry
+            .parent
+            .as_ref()
+            .is_some_and(|parent| parent.entry_type == EntryType::Blog);
+        let is_newspaper = !is_blog
+            && (metadata.is_newspaper()
+                || self
+                    .dom
+                    .schema_metadata()?
+                    .find(schema_org::NEWS_ARTICLE_TYPES)
+                    .is_some());
+
         let OgpMetadata {
             title,
             kind,
@@ -144,18 +218,18 @@ impl CitationBuilder {
             article,
             book,
             profile,
-        } = self.dom.ogp_metadata()?;
+        } = metadata;
 
         if let Some(title) = title {
-            self.title.first = Some(title);
+            self.entry.title.first = Some(title);
         }
 
         if let Some(url) = url {
-            self.url.first = Some(url);
+            self.entry.url.first = Some(url);
         }
 
         if let Some(language) = locale {
-            self.language.first = Some(language);
+            self.entry.language.first = Some(language);
         }
 
         let OgpImage {
@@ -183,8 +257,8 @@ impl CitationBuilder {
         } = video;
 
         if let Some(url) = url {
-            self.entry_type = EntryType::Video;
-            self.url.first = Some(url);
+            self.entry.entry_type = EntryType::Video;
+            self.entry.url.first = Some(url);
         }
 
         let OgpArticle {
@@ -193,17 +267,35 @@ impl CitationBuilder {
             modified_time,
             expiration_time,
             authors,
+            publisher: _,
             section,
             tags,
         } = article;
 
         if is_article {
-            self.entry_type = EntryType::Article;
+            self.entry.entry_type = EntryType::Article;
+        }
+
+        if let Some(published_time) = published_time {
+            self.entry.date.first = Some(published_time.into());
+        }
+
+        // e.g. "Politics", while the sections of blogs are e.g. "posts"
+        if is_newspaper && let Some(section) = section {
+            self.entry.note.first = Some(section);
+        }
+
+        if is_article
+            && is_newspaper
+            && let Some(site_name) = site_name
+        {
+            let newspaper = self.entry.parent_mut(EntryType::Newspaper);
+            newspaper.title.first = Some(site_name.into());
         }
 
         if authors.len() > 0 {
-            self.entry_type = EntryType::Article;
-            self.authors.first = Some(authors);
+            self.entry.entry_type = EntryType::Article;
+            self.entry.authors.first = Some(authors);
         }
 
         let OgpBook {
@@ -215,126 +307,1462 @@ impl CitationBuilder {
         } = book;
 
         if is_book {
-            self.entry_type = EntryType::Book;
+            self.entry.entry_type = EntryType::Book;
         }
 
         if authors.len() > 0 {
-            self.entry_type = EntryType::Book;
-            self.authors.first = Some(authors);
+            self.entry.entry_type = EntryType::Book;
+            self.entry.authors.first = Some(authors);
         }
 
         if let Some(isbn) = isbn {
-            self.entry_type = EntryType::Book;
-            self.isbn.first = Some(isbn);
+            self.entry.entry_type = EntryType::Book;
+            self.entry.isbn.first = Some(isbn);
         }
 
         Ok(self)
     }
 
-    pub fn build(self) -> Citation {
-        Entry::from(self).into()
-    }
-}
+    // proceedings listed by DBLP or the ACM Digital Library are only read as far as
+    // they are described by highwire tags or schema.org objects, as neither has a format of its own
+    pub fn with_highwire_metadata(mut self) -> MetaResult<Self> {
+        let metadata = self.dom.highwire_metadata()?;
+        // highwire has no tag for the location of the conference
+        let location = schema_org::paper_event(&self.dom.schema_metadata()?)
+            .and_then(|event| event.place("location"));
+        let is_conference_paper = metadata.is_conference_paper();
+        let is_technical_report = metadata.is_technical_report();
 
-impl From<CitationBuilder> for Entry {
-    fn from(citation: CitationBuilder) -> Self {
-        let CitationBuilder {
-            dom: _,
-            entry_type,
+        let HighwireMetadata {
             title,
             authors,
+            editors,
+            publication_date,
             date,
+            conference_title,
+            conference,
+            technical_report_institution,
+            technical_report_number,
+            publisher,
+            firstpage,
+            lastpage,
+            doi,
+            isbn,
+            language,
+        } = metadata;
+
+        let mut errors = Errors::new();
+        let entry = &mut self.entry;
+
+        if let Some(title) = title {
+            entry.title.first = Some(title);
+        }
+
+        if !authors.is_empty() {
+            entry.authors.first = Some(authors);
+        }
+
+        if let Some(date) = publication_date.or(date) {
+            entry.date.first = Some(date);
+        }
+
+        if let Some(firstpage) = firstpage {
+            entry.page_range.first =
+                errors.capture(PageRange::new(&firstpage, lastpage.as_deref()));
+        }
+
+        if let Some(doi) = doi {
+            entry.doi.first = Some(doi);
+        }
+
+        if let Some(language) = language {
+            entry.language.first = Some(language);
+        }
+
+        if is_technical_report {
+            entry.entry_type = EntryType::Report;
+        }
+
+        // below the number of a detected standard, e.g. "rfc9110" of the IETF datatracker
+        if let Some(institution) = technical_report_institution {
+            entry.organization.second = Some(institution);
+        }
+
+        if let Some(number) = technical_report_number {
+            entry.serial_number.second = Some(number);
+        }
+
+        if is_conference_paper {
+            entry.entry_type = EntryType::Article;
+
+            let year = entry.date.first.map(PartialDate::year_only);
+            let proceedings = entry.parent_mut(EntryType::Proceedings);
+
+            if let Some(title) = conference_title.or(conference) {
+                proceedings.title.first = Some(title.into());
+            }
+
+            if !editors.is_empty() {
+                proceedings.editors.first = Some(editors);
+            }
+
+            if let Some(publisher) = publisher {
+                proceedings.publisher.first = Some(publisher);
+            }
+
+            if let Some(isbn) = isbn {
+                proceedings.isbn.first = Some(isbn);
+            }
+
+            if let Some(location) = location {
+                proceedings.location.second = Some(location);
+            }
+
+            if let Some(year) = year {
+                proceedings.date.first = Some(year);
+            }
+        } else {
+            if !editors.is_empty() {
+                entry.editors.first = Some(editors);
+            }
+
+            if let Some(publisher) = publisher {
+                entry.publisher.first = Some(publisher);
+            }
+
+            if let Some(isbn) = isbn {
+                entry.isbn.first = Some(isbn);
+            }
+        }
+
+        errors.print_failure();
+
+        Ok(self)
+    }
+
+    pub fn with_schema_metadata(mut self) -> MetaResult<Self> {
+        let schema = self.dom.schema_metadata()?;
+
+        if let Some(paper) = schema.find(&["ScholarlyArticle"]) {
+            schema_org::apply_paper(&mut self.entry, &schema, paper);
+        } else if let Some(article) = schema.find(schema_org::NEWS_ARTICLE_TYPES) {
+            schema_org::apply_news_article(&mut self.entry, article);
+        } else if let Some(dataset) = schema.find(&["Dataset"]) {
+            schema_org::apply_dataset(&mut self.entry, dataset);
+        } else if let Some(software) = schema.find(&["SoftwareSourceCode"]) {
+            schema_org::apply_software(&mut self.entry, software);
+        } else if let Some(artwork) = schema.find(schema_org::ARTWORK_TYPES) {
+            schema_org::apply_artwork(&mut self.entry, artwork);
+        } else if let Some(component) = schema.find(&["ArchiveComponent"]) {
+            schema_org::apply_archive_component(&mut self.entry, component);
+        } else if let Some(event) = schema_org::main_event(&schema, self.dom.url()) {
+            schema_org::apply_event(&mut self.entry, &event);
+        }
+
+        Ok(self)
+    }
+
+    pub fn with_dublin_core_metadata(mut self) -> MetaResult<Self> {
+        let metadata = self.dom.dublin_core_metadata()?;
+        let is_dataset = metadata.is_dataset();
+
+        if let Some((_, number)) = metadata.report_series() {
+            self.entry.entry_type = EntryType::Report;
+            self.entry.serial_number.second = Some(number.to_owned());
+
+            if let Some(publisher) = &metadata.publisher {
+                self.entry.organization.second = Some(publisher.clone());
+            }
+        }
+
+        if is_dataset {
+            let DublinCoreMetadata {
+                title,
+                creators,
+                date,
+                kind,
+                publisher,
+                doi,
+                rights,
+                series,
+            } = metadata;
+            let entry = &mut self.entry;
+
+            entry.entry_type = EntryType::Misc;
+
+            if let Some(title) = title {
+                entry.title.second = Some(title);
+            }
+
+            if !creators.is_empty() {
+                entry.authors.second = Some(creators);
+            }
+
+            if let Some(date) = date {
+                entry.date.second = Some(date);
+            }
+
+            if let Some(publisher) = publisher {
+                entry.publisher.second = Some(publisher);
+            }
+
+            if let Some(doi) = doi {
+                entry.doi.second = Some(doi.into());
+            }
+
+            if let Some(rights) = rights {
+                entry.license.second = Some(rights);
+            }
+        }
+
+        Ok(self)
+    }
+
+    pub fn with_working_paper(mut self) -> MetaResult<Self> {
+        if let Some(WorkingPaper {
+            institution,
+            number,
+        }) = WorkingPaper::detect(&self.dom)
+        {
+            self.entry.entry_type = EntryType::Report;
+            self.entry.organization.third = Some(institution.to_owned());
+            self.entry.serial_number.third = Some(number);
+        }
+
+        Ok(self)
+    }
+
+    pub fn with_blog(mut self) -> MetaResult<Self> {
+        let Blog {
+            platform,
+            name,
+            owner,
+            series,
+        } = match Blog::detect(&self.dom)? {
+            Some(blog) => blog,
+            None => return Ok(self),
+        };
+
+        self.entry.entry_type = EntryType::Article;
+
+        if let Some(BlogSeries { name, part }) = series {
+            if let Some(part) = part {
+                self.entry.volume.second = Some(part);
+            }
+
+            if let Some(name) = name {
+                self.entry.note.second = Some(name);
+            }
+        }
+
+        let blog = self.entry.parent_mut(EntryType::Blog);
+
+        if let Some(name) = name {
+            blog.title.second = Some(name.into());
+        }
+
+        if let Some(owner) = owner {
+            blog.authors.second = Some(vec![owner]);
+        }
+
+        Ok(self)
+    }
+
+    pub fn with_data_repository(mut self) -> MetaResult<Self> {
+        if let Some(record) = ZenodoRecord::detect(&self.dom) {
+            self.entry.publisher.third = Some("Zenodo".to_owned());
+            self.entry.doi.third = Some(record.doi());
+        }
+
+        if let Some(CkanDataset {
+            title,
+            authors,
+            organization,
+            portal,
+            created,
+            version,
+            license,
+            doi,
+        }) = CkanDataset::detect(&self.dom)?
+        {
+            let entry = &mut self.entry;
+
+            entry.entry_type = EntryType::Misc;
+
+            if let Some(title) = title {
+                entry.title.third = Some(title.into());
+            }
+
+            if !authors.is_empty() {
+                entry.authors.third = Some(authors);
+            }
+
+            if let Some(publisher) = organization.or(portal) {
+                entry.publisher.third = Some(publisher);
+            }
+
+            if let Some(created) = created {
+                entry.date.third = Some(created);
+            }
+
+            if let Some(version) = version {
+                entry.serial_number.third = Some(version);
+            }
+
+            if let Some(license) = license {
+                entry.license.third = Some(license);
+            }
+
+            if let Some(doi) = doi {
+                entry.doi.third = Some(doi.into());
+            }
+        }
+
+        Ok(self)
+    }
+
+    pub fn with_software_repository(mut self) -> MetaResult<Self> {
+        let SoftwareRepository {
+            forge,
+            owner,
+            name,
+            url,
+            description,
+            version,
+            released,
+            license,
+            language,
+        } = match SoftwareRepository::detect(&self.dom)? {
+            Some(repository) => repository,
+            None => return Ok(self),
+        };
+        let mut errors = Errors::new();
+        let entry = &mut self.entry;
+
+        entry.entry_type = EntryType::Repository;
+        // the title of a forge page is not the name of the repository, e.g. "GitHub - owner/name"
+        entry.title.first = Some(name.into());
+        entry.authors.first = Some(vec![Name::literal(owner)]);
+        entry.publisher.first = Some(forge.name().to_owned());
+        entry.url.first = Some(url);
+
+        if let Some(version) = version {
+            entry.serial_number.first = Some(version);
+        }
+
+        if let Some(released) = released {
+            entry.date.first = Some(released);
+        }
+
+        if let Some(license) = license {
+            entry.license.first = Some(license);
+        }
+
+        let note = match (description, language) {
+            (Some(description), Some(language)) => Some(format!("{description}, {language}")),
+            (description, language) => description.or(language),
+        };
+        if let Some(note) = note {
+            entry.note.first = Some(note);
+        }
+
+        // the files the authors cite their software with take precedence over the page
+        if let Some(codemeta) = errors.capture(repository::codemeta(&self.dom)).flatten() {
+            schema_org::apply_software(entry, &codemeta);
+        }
+
+        if let Some(CitationFile {
+            title,
+            authors,
+            version,
+            date_released,
+            doi,
+            license,
+            repository,
+        }) = errors
+            .capture(repository::citation_file(&self.dom))
+            .flatten()
+        {
+            if let Some(title) = title {
+                entry.title.first = Some(title.into());
+            }
+
+            if !authors.is_empty() {
+                entry.authors.first = Some(authors);
+            }
+
+            if let Some(version) = version {
+                entry.serial_number.first = Some(version);
+            }
+
+            if let Some(date) = date_released {
+                entry.date.first = Some(date);
+            }
+
+            if let Some(doi) = doi {
+                entry.doi.first = Some(doi.into());
+            }
+
+            if let Some(license) = license {
+                entry.license.first = Some(license);
+            }
+
+            if let Some(repository) = repository {
+                entry.url.first = Some(repository);
+            }
+        }
+
+        errors.print_failure();
+
+        Ok(self)
+    }
+
+    pub fn with_rust_crate(mut self) -> MetaResult<Self> {
+        let RustCrate {
+            page,
+            name,
+            version,
+            owners,
+            license,
+            repository,
+            item,
+            url,
+        } = match RustCrate::detect(&self.dom)? {
+            Some(rust_crate) => rust_crate,
+            None => return Ok(self),
+        };
+        let entry = &mut self.entry;
+
+        let (entry_type, publisher) = match page {
+            CratePage::Registry => (EntryType::Repository, "crates.io"),
+            CratePage::Documentation => (EntryType::Reference, "docs.rs"),
+        };
+        entry.entry_type = entry_type;
+        entry.publisher.first = Some(publisher.to_owned());
+        entry.title.first = Some(item.unwrap_or(name).into());
+        entry.url.first = Some(url);
+
+        if let Some(version) = version {
+            entry.serial_number.first = Some(version);
+        }
+
+        if !owners.is_empty() {
+            entry.authors.first = Some(owners);
+        }
+
+        if let Some(license) = license {
+            entry.license.first = Some(license);
+        }
+
+        if let Some(repository) = repository {
+            entry.note.first = Some(format!("Source: {repository}"));
+        }
+
+        Ok(self)
+    }
+
+    pub fn with_docs_chapter(mut self) -> MetaResult<Self> {
+        let DocsChapter {
+            generator,
+            book,
+            title,
+            number,
+        } = match DocsChapter::detect(&self.dom)? {
+            Some(chapter) => chapter,
+            None => return Ok(self),
+        };
+
+        self.entry.entry_type = EntryType::Chapter;
+
+        if let Some(title) = title {
+            self.entry.title.first = Some(title.into());
+        }
+
+        // hayagriva has no field for the number of a chapter
+        if let Some(number) = number {
+            self.entry.serial_number.first = Some(number);
+        }
+
+        let parent = self.entry.parent_mut(EntryType::Book);
+
+        if let Some(book) = book {
+            parent.title.first = Some(book.into());
+        }
+
+        Ok(self)
+    }
+
+    pub fn with_catalogue_book(mut self) -> MetaResult<Self> {
+        let CatalogueBook {
+            title,
+            authors,
             editors,
-            // affiliated_persons,
             publisher,
-            location,
+            place,
+            edition,
+            date,
+            series,
+            series_number,
+            volume_total,
+            pages,
+            mut isbns,
+            url,
+            ..
+        } = match CatalogueBook::detect(&self.dom)? {
+            Some(book) => book,
+            None => return Ok(self),
+        };
+        let entry = &mut self.entry;
+
+        entry.entry_type = EntryType::Book;
+        entry.url.first = Some(url);
+
+        if let Some(title) = title {
+            entry.title.first = Some(title.into());
+        }
+
+        if !authors.is_empty() {
+            entry.authors.first = Some(authors);
+        }
+
+        if !editors.is_empty() {
+            entry.editors.first = Some(editors);
+        }
+
+        if let Some(publisher) = publisher {
+            entry.publisher.first = Some(publisher);
+        }
+
+        if let Some(place) = place {
+            entry.location.first = Some(place);
+        }
+
+        if let Some(edition) = edition {
+            entry.edition.first = Some(edition);
+        }
+
+        if let Some(date) = date {
+            entry.date.first = Some(date);
+        }
+
+        if let Some(pages) = pages {
+            entry.page_total.first = Some(pages);
+        }
+
+        if let Some(volume_total) = volume_total {
+            entry.volume_total.first = Some(volume_total);
+        }
+
+        // hayagriva has a single isbn, the others of the edition are kept in the note
+        if let Some(index) = isbns.iter().position(|isbn| matches!(isbn, Isbn::_13(_))) {
+            isbns.swap(0, index);
+        }
+        let mut isbns = isbns.into_iter();
+        if let Some(isbn) = isbns.next() {
+            entry.isbn.first = Some(isbn);
+        }
+        let others = isbns.map(|isbn| isbn.to_string()).collect::<Vec<_>>();
+        if !others.is_empty() {
+            entry.note.first = Some(format!("ISBN: {}", others.join(", ")));
+        }
+
+        // the number within the series is the volume of the book
+        if let Some(series) = series {
+            if let Some(number) = series_number {
+                entry.volume.first = Some(number);
+            }
+
+            let parent = entry.parent_mut(EntryType::Book);
+            parent.title.first = Some(series.into());
+        }
+
+        Ok(self)
+    }
+
+    pub fn with_issue(mut self) -> MetaResult<Self> {
+        let issue = match Issue::detect(&self.dom)? {
+            Some(issue) => issue,
+            None => return Ok(self),
+        };
+        let entry = &mut self.entry;
+
+        // the reference is stable, opposed to the title which may be edited at any time
+        entry.serial_number.first = Some(issue.reference());
+        entry.entry_type = match issue.kind {
+            IssueKind::Issue | IssueKind::PullRequest => EntryType::Thread,
+            IssueKind::Rfc => EntryType::Misc,
+        };
+
+        let Issue {
+            forge,
+            owner,
+            name,
+            title,
+            author,
+            opened,
+            state,
+            url,
+            repository_url,
+            ..
+        } = issue;
+        entry.url.first = Some(url);
+
+        if let Some(title) = title {
+            entry.title.first = Some(title.into());
+        }
+
+        if let Some(author) = author {
+            entry.authors.first = Some(vec![author]);
+        }
+
+        if let Some(opened) = opened {
+            entry.date.first = Some(opened);
+        }
+
+        // the description of the repository does not describe the issue
+        entry.note.first = state.map(|state| state.name().to_owned());
+
+        let repository = entry.parent_mut(EntryType::Repository);
+        repository.title.first = Some(name.into());
+        repository.authors.first = Some(vec![Name::literal(owner)]);
+        repository.publisher.first = Some(forge.name().to_owned());
+        repository.url.first = Some(repository_url);
+
+        Ok(self)
+    }
+
+    pub fn with_standard(mut self) -> MetaResult<Self> {
+        let Standard {
             organization,
+            number,
+            title,
+            authors,
+            editors,
+            date,
+            status,
             url,
-            serial_number,
-            isbn,
+            ..
+        } = match Standard::detect(&self.dom)? {
+            Some(standard) => standard,
+            None => return Ok(self),
+        };
+        let entry = &mut self.entry;
+
+        entry.entry_type = EntryType::Report;
+        entry.organization.first = Some(organization);
+        entry.url.first = Some(url);
+
+        if let Some(number) = number {
+            entry.serial_number.first = Some(number);
+        }
+
+        if let Some(title) = title {
+            entry.title.first = Some(title.into());
+        }
+
+        if !authors.is_empty() {
+            entry.authors.first = Some(authors);
+        }
+
+        if !editors.is_empty() {
+            entry.editors.first = Some(editors);
+        }
+
+        if let Some(date) = date {
+            entry.date.first = Some(date);
+        }
+
+        if let Some(status) = status {
+            entry.note.first = Some(status);
+        }
+
+        Ok(self)
+    }
+
+    pub fn with_artwork(mut self) -> MetaResult<Self> {
+        let Artwork {
+            title,
+            artist,
+            created,
+            medium,
+            dimensions,
+            institution,
+            accession_number,
+            url,
+            ..
+        } = match Artwork::detect(&self.dom)? {
+            Some(artwork) => artwork,
+            None => return Ok(self),
+        };
+        let entry = &mut self.entry;
+
+        entry.entry_type = EntryType::Artwork;
+        entry.url.first = Some(url);
+
+        if let Some(title) = title {
+            entry.title.first = Some(title.into());
+        }
+
+        if let Some(artist) = artist {
+            entry.authors.first = Some(vec![artist]);
+        }
+
+        if let Some(created) = created {
+            entry.date.first = Some(created);
+        }
+
+        // hayagriva has no fields for the material and the dimensions of an object
+        let note = [medium, dimensions]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        if !note.is_empty() {
+            entry.note.first = Some(note.join(", "));
+        }
+
+        if let Some(institution) = institution {
+            entry.organization.first = Some(institution);
+        }
+
+        if let Some(number) = accession_number {
+            entry.serial_number.first = Some(number);
+        }
+
+        Ok(self)
+    }
+
+    pub fn with_archival_record(mut self) -> MetaResult<Self> {
+        let record = match ArchivalRecord::detect(&self.dom)? {
+            Some(record) => record,
+            None => return Ok(self),
+        };
+        let archive_location = record.archive_location();
+        let ArchivalRecord {
+            title,
+            creators,
+            date,
+            repository,
+            manifest,
+            url,
+            ..
+        } = record;
+        let entry = &mut self.entry;
+
+        entry.entry_type = EntryType::Manuscript;
+        entry.url.first = Some(url);
+
+        if let Some(title) = title {
+            entry.title.first = Some(title.into());
+        }
+
+        if !creators.is_empty() {
+            entry.authors.first = Some(creators);
+        }
+
+        if let Some(date) = date {
+            entry.date.first = Some(date);
+        }
+
+        if let Some(repository) = repository {
+            entry.archive.first = Some(repository);
+        }
+
+        // hayagriva 0.3 has no call-number field,
+        // so the call number is cited as part of the archive location
+        if let Some(location) = archive_location {
+            entry.archive_location.first = Some(location);
+        }
+
+        if let Some(manifest) = manifest {
+            entry.note.first = Some(format!("IIIF manifest: {manifest}"));
+        }
+
+        Ok(self)
+    }
+
+    pub fn with_wiki_article(mut self) -> MetaResult<Self> {
+        let WikiArticle {
+            site_name,
+            title,
             language,
-            note,
-        } = citation;
+            revision,
+            modified,
+            url,
+        } = match WikiArticle::detect(&self.dom)? {
+            Some(article) => article,
+            None => return Ok(self),
+        };
+        let entry = &mut self.entry;
 
-        let highest_title = title.highest();
+        entry.entry_type = EntryType::Reference;
+        entry.title.first = Some(title.into());
+        // the permanent link of the revision instead of the article, which may change
+        entry.url.first = Some(url);
+        entry.serial_number.first = Some(revision.to_string());
 
-        let key = highest_title
-            .as_ref()
-            .map(calculate_key)
-            .unwrap_or(PLACEHOLDER.to_owned());
+        if let Some(language) = language {
+            entry.language.first = Some(language);
+        }
 
-        let mut entry = Entry::new(&key, entry_type);
+        if let Some(modified) = modified {
+            entry.date.first = Some(modified);
+        }
 
-        if let Some(title) = highest_title {
-            entry.set_title(title.into());
+        if let Some(site_name) = site_name {
+            let encyclopedia = entry.parent_mut(EntryType::Reference);
+            encyclopedia.title.first = Some(site_name.into());
         }
 
-        if let Some(authors) = authors.highest() {
-            let item = authors.into_iter().map(Into::into).collect();
-            entry.set_authors(item);
+        Ok(self)
+    }
+
+    // runs after the highwire metadata, which cites the entry as an article of its own
+    pub fn with_reference_entry(mut self) -> MetaResult<Self> {
+        let ReferenceEntry {
+            work,
+            title,
+            authors,
+            edition,
+            revised,
+            doi,
+            url,
+        } = match ReferenceEntry::detect(&self.dom)? {
+            Some(entry) => entry,
+            None => return Ok(self),
+        };
+        let entry = &mut self.entry;
+
+        entry.entry_type = EntryType::Entry;
+        // the archived edition instead of the entry, which is revised over time
+        entry.url.first = Some(url);
+
+        if let Some(title) = title {
+            entry.title.first = Some(title.into());
         }
 
-        if let Some(date) = date.highest() {
-            entry.set_date(date.into());
+        if !authors.is_empty() {
+            entry.authors.first = Some(authors);
+        }
+
+        if let Some(revised) = revised {
+            entry.date.first = Some(revised);
+        }
+
+        if let Some(doi) = doi {
+            entry.doi.first = Some(doi.into());
+        }
+
+        let reference = entry.parent_mut(EntryType::Reference);
+        reference.title.first = Some(work.name().to_owned().into());
+        reference.publisher.first = Some(work.publisher().to_owned());
+
+        if let Some(edition) = edition {
+            reference.edition.first = Some(edition);
         }
 
-        if let Some(editors) = editors.highest() {
-            let item = editors.into_iter().map(Into::into).collect();
-            entry.set_editors(item);
+        Ok(self)
+    }
+
+    pub fn with_video(mut self) -> MetaResult<Self> {
+        let Video {
+            platform,
+            id,
+            channel,
+            uploaded,
+            runtime,
+            url,
+        } = match Video::detect(&self.dom)? {
+            Some(video) => video,
+            None => return Ok(self),
+        };
+        let entry = &mut self.entry;
+
+        entry.entry_type = EntryType::Video;
+        entry.publisher.first = Some(platform.name().to_owned());
+        entry.url.first = Some(url);
+
+        if let Some(channel) = channel {
+            entry.authors.first = Some(vec![channel]);
         }
 
-        if let Some(publisher) = publisher.highest() {
-            entry.set_publisher(publisher.into());
+        if let Some(uploaded) = uploaded {
+            entry.date.first = Some(uploaded);
         }
-        if let Some(location) = location.highest() {
-            entry.set_location(location.into());
+
+        if let Some(runtime) = runtime {
+            entry.runtime.first = Some(runtime);
         }
 
-        if let Some(orga) = organization.highest() {
-            entry.set_organization(orga);
+        Ok(self)
+    }
+
+    pub fn with_social_post(mut self) -> MetaResult<Self> {
+        let SocialThread {
+            network,
+            post,
+            ancestors,
+        } = match SocialThread::detect(&self.dom)? {
+            Some(thread) => thread,
+            None => return Ok(self),
+        };
+
+        // hayagriva has no entry type for posts other than tweets
+        self.entry.entry_type = EntryType::Tweet;
+        self.entry.publisher.first = Some(network.name().to_owned());
+        apply_post(&mut self.entry, post);
+
+        // the post replied to last is the closest parent
+        let mut parent = &mut self.entry;
+        for post in ancestors.into_iter().rev() {
+            parent = parent.parent_mut(EntryType::Thread);
+            apply_post(parent, post);
         }
 
-        if let Some(url) = url.highest() {
-            entry.set_url(into_qualified(url));
+        Ok(self)
+    }
+
+    pub fn with_forum_thread(mut self) -> MetaResult<Self> {
+        let ForumThread {
+            forum,
+            site_name,
+            title,
+            question,
+            answer,
+        } = match ForumThread::detect(&self.dom)? {
+            Some(thread) => thread,
+            None => return Ok(self),
+        };
+        let entry = &mut self.entry;
+
+        entry.entry_type = EntryType::Thread;
+        entry.publisher.first = site_name.clone();
+
+        let answer = match answer {
+            Some(answer) => answer,
+            None => {
+                entry.title.first = title.map(Into::into);
+                apply_forum_post(entry, question);
+                return Ok(self);
+            }
+        };
+
+        // an answer has no title of its own and is cited within the thread of its question
+        let reply = match forum {
+            Forum::StackExchange => "Answer to",
+            Forum::Discourse | Forum::PhpBb => "Reply to",
+        };
+        entry.title.first = title
+            .as_ref()
+            .map(|title| format!("{reply} \"{title}\"").into());
+        apply_forum_post(entry, answer);
+
+        let thread = entry.parent_mut(EntryType::Thread);
+        thread.title.first = title.map(Into::into);
+        thread.publisher.first = site_name;
+        apply_forum_post(thread, question);
+
+        Ok(self)
+    }
+
+    pub fn with_mailing_list_message(mut self) -> MetaResult<Self> {
+        let message = match MailingListMessage::detect(&self.dom)? {
+            Some(message) => message,
+            None => return Ok(self),
+        };
+        let entry = &mut self.entry;
+
+        entry.entry_type = EntryType::Thread;
+        entry.title.first = message.title().map(Into::into);
+
+        let MailingListMessage {
+            list,
+            list_url,
+            sender,
+            date,
+            message_id,
+            url,
+            ..
+        } = message;
+        entry.url.first = Some(url);
+
+        if let Some(sender) = sender {
+            entry.authors.first = Some(vec![sender]);
         }
 
-        if let Some(serial_number) = serial_number.highest() {
-            entry.set_serial_number(serial_number);
+        if let Some(date) = date {
+            entry.date.first = Some(date);
         }
-        if let Some(isbn) = isbn.highest() {
-            entry.set_isbn(isbn.to_string());
+
+        // the message can be found in any other archive of the list by its id
+        if let Some(message_id) = message_id {
+            entry.note.first = Some(format!("Message-ID: <{message_id}>"));
         }
 
-        if let Some(language) = language.highest() {
-            entry.set_language(language);
+        let thread = entry.parent_mut(EntryType::Thread);
+        thread.title.first = Some(list.into());
+        thread.url.first = list_url;
+
+        Ok(self)
+    }
+
+    // runs after the highwire metadata, whose date is the one of the first version
+    pub fn with_arxiv_paper(mut self) -> MetaResult<Self> {
+        let paper = match ArxivPaper::detect(&self.dom)? {
+            Some(paper) => paper,
+            None => return Ok(self),
+        };
+        let entry = &mut self.entry;
+
+        // versions may differ substantively and are thus cited explicitly
+        entry.entry_type = EntryType::Article;
+        entry.serial_number.first = Some(paper.versioned_id());
+        entry.publisher.first = Some("arXiv".to_owned());
+
+        let ArxivPaper {
+            submitted,
+            category,
+            journal_ref,
+            doi,
+            url,
+            ..
+        } = paper;
+        entry.url.first = Some(url);
+
+        if let Some(submitted) = submitted {
+            entry.date.first = Some(submitted);
         }
 
-        if let Some(note) = note.highest() {
-            entry.set_note(note);
+        if let Some(doi) = doi {
+            entry.doi.first = Some(doi.into());
         }
 
-        entry
+        let journal_ref = journal_ref.map(|journal_ref| format!("Published in {journal_ref}"));
+        entry.note.first = match (category, journal_ref) {
+            (Some(category), Some(journal_ref)) => Some(format!("{category}, {journal_ref}")),
+            (category, journal_ref) => category.or(journal_ref),
+        };
+
+        Ok(self)
+    }
+
+    pub fn build(self) -> Citation {
+        Entry::from(self).into()
+    }
+}
+
+impl From<CitationBuilder> for Entry {
+    fn from(citation: CitationBuilder) -> Self {
+        citation.entry.into()
+    }
+}
+
+fn apply_post(entry: &mut EntryBuilder, post: SocialPost) {
+    if let Some(title) = post.title() {
+        entry.title.first = Some(title.into());
+    }
+
+    let SocialPost {
+        author,
+        text,
+        published,
+        url,
+    } = post;
+
+    if let Some(author) = author {
+        entry.authors.first = Some(vec![author]);
+    }
+
+    if let Some(published) = published {
+        entry.date.first = Some(published);
+    }
+
+    if let Some(url) = url {
+        entry.url.first = Some(url);
     }
 }
 
-// TODO remove special characters
-fn calculate_key(title: &Title) -> String {
-    // TODO add author to key if found
-    let key = title
-        .canonical
-        .to_ascii_lowercase()
-        .replace(|c: char| !c.is_ascii(), "");
-    let mut splitted_key = key.trim().split_whitespace().enumerate();
+fn apply_forum_post(entry: &mut EntryBuilder, post: ForumPost) {
+    let ForumPost {
+        author,
+        published,
+        url,
+    } = post;
 
-    let mut key = String::new();
-    while let Some((counter, part)) = splitted_key.next() {
-        key.push_str(part);
-        key.push('-');
+    if let Some(author) = author {
+        entry.authors.first = Some(vec![author]);
+    }
 
-        if counter == 3 {
-            break;
-        }
+    if let Some(published) = published {
+        entry.date.first = Some(published);
+    }
+
+    entry.url.first = Some(url);
+}
+
+#[cfg(test)]
+mod test {
+    use super::CitationBuilder;
+    use crate::{dom::Dom, entry::EntryBuilder, meta::value::PartialDate};
+    use hayagriva::types::EntryType;
+    use url::Url;
+
+    const CONFERENCE_PAPER_HTML: &str = r#"<html><head>
+        <meta name="citation_title" content="Fast Parsing">
+        <meta name="citation_author" content="Doe, Jane">
+        <meta name="citation_publication_date" content="2019/06/15">
+        <meta name="citation_conference_title" content="Proceedings of the Example Conference">
+        <meta name="citation_editor" content="Roe, John">
+        <meta name="citation_publisher" content="ACM">
+        <meta name="citation_firstpage" content="12">
+        <meta name="citation_lastpage" content="20">
+        <script type="application/ld+json">[
+            {"@type": "Event", "name": "Reading Group",
+             "location": {"@type": "Place", "address": {"addressLocality": "Berlin"}}},
+            {"@type": "ScholarlyArticle", "name": "Fast Parsing",
+             "recordedAt": {"@id": "https://example.org/conference"}},
+            {"@type": "Event", "@id": "https://example.org/conference",
+             "name": "Example Conference 2019",
+             "location": {"@type": "Place", "address": {"addressLocality": "London",
+                          "addressCountry": "UK"}}}
+        ]</script>
+    </head><body></body></html>"#;
+
+    #[test]
+    fn conference_paper_proceedings() {
+        let dom = Dom::parse(CONFERENCE_PAPER_HTML, "https://example.org/paper").unwrap();
+        let entry = CitationBuilder::new(dom)
+            .with_highwire_metadata()
+            .unwrap()
+            .entry;
+
+        assert_eq!(entry.entry_type, EntryType::Article);
+        assert!(entry.page_range.highest().is_some());
+
+        let proceedings = entry.parent.unwrap();
+        assert_eq!(proceedings.entry_type, EntryType::Proceedings);
+        assert_eq!(
+            proceedings.title.highest().map(|title| title.canonical),
+            Some("Proceedings of the Example Conference".to_owned())
+        );
+        assert_eq!(
+            proceedings.editors.highest().map(|editors| editors.len()),
+            Some(1)
+        );
+        assert_eq!(proceedings.publisher.highest().as_deref(), Some("ACM"));
+        assert_eq!(
+            proceedings.location.highest().as_deref(),
+            Some("London, UK")
+        );
+        assert_eq!(
+            proceedings.date.highest(),
+            Some(PartialDate {
+                year: 2019,
+                month: None,
+                day: None,
+            })
+        );
+    }
+
+    #[test]
+    fn working_paper_report() {
+        let html = r#"<html><head>
+            <meta name="DC.title" content="Trade and Growth">
+            <meta name="DC.publisher" content="Kiel Institute for the World Economy">
+            <meta name="DC.relation.ispartofseries" content="Kiel Working Paper; No. 2140">
+        </head></html>"#;
+        let dom = Dom::parse(html, "https://www.econstor.eu/handle/10419/123").unwrap();
+        let entry = CitationBuilder::new(dom)
+            .with_working_paper()
+            .unwrap()
+            .with_dublin_core_metadata()
+            .unwrap()
+            .entry;
+
+        assert_eq!(entry.entry_type, EntryType::Report);
+        assert_eq!(entry.serial_number.highest().as_deref(), Some("2140"));
+        assert_eq!(
+            entry.organization.highest().as_deref(),
+            Some("Kiel Institute for the World Economy")
+        );
+
+        let dom = Dom::parse("<html></html>", "https://www.nber.org/papers/w31234").unwrap();
+        let entry = CitationBuilder::new(dom)
+            .with_working_paper()
+            .unwrap()
+            .entry;
+        assert_eq!(entry.entry_type, EntryType::Report);
+        assert_eq!(entry.serial_number.highest().as_deref(), Some("w31234"));
+    }
+
+    #[test]
+    fn newspaper_article() {
+        let newspaper = |html: &str| {
+            let dom = Dom::parse(html, "https://example.org/article").unwrap();
+            let entry = CitationBuilder::new(dom).with_ogp_metadata().unwrap().entry;
+            entry
+                .parent
+                .filter(|parent| parent.entry_type == EntryType::Newspaper)
+                .and_then(|parent| parent.title.highest())
+                .map(|title| title.canonical)
+        };
+
+        let publisher = r#"<html><head>
+            <meta property="og:type" content="article">
+            <meta property="og:site_name" content="The Daily Example">
+            <meta property="article:publisher" content="https://www.facebook.com/dailyexample">
+            <meta property="article:section" content="Politics">
+        </head></html>"#;
+        assert_eq!(newspaper(publisher).as_deref(), Some("The Daily Example"));
+
+        let schema = r#"<html><head>
+            <meta property="og:type" content="article">
+            <meta property="og:site_name" content="Example">
+            <script type="application/ld+json">{"@type": "NewsArticle"}</script>
+        </head></html>"#;
+        assert_eq!(newspaper(schema).as_deref(), Some("Example"));
+
+        // neither journals nor blogs are newspapers because of their name
+        let journal = r#"<html><head>
+            <meta property="og:type" content="article">
+            <meta property="og:site_name" content="Journal of Example Studies">
+        </head></html>"#;
+        assert_eq!(newspaper(journal), None);
+    }
+
+    #[test]
+    fn blog_post_with_section() {
+        // the opengraph template of Hugo files every post under its section
+        let html = r#"<html><head>
+            <meta name="generator" content="Hugo 0.120.4">
+            <meta property="og:type" content="article">
+            <meta property="og:site_name" content="Jane's Blog">
+            <meta property="article:section" content="posts">
+            <meta name="author" content="Jane Doe">
+        </head></html>"#;
+        let dom = Dom::parse(html, "https://jane.example.org/posts/async-rust/").unwrap();
+        let entry = CitationBuilder::new(dom)
+            .with_blog()
+            .unwrap()
+            .with_ogp_metadata()
+            .unwrap()
+            .entry;
+
+        let blog = entry.parent.unwrap();
+        assert_eq!(blog.entry_type, EntryType::Blog);
+        assert_eq!(blog.authors.highest().map(|authors| authors.len()), Some(1));
+        assert_eq!(entry.note.first, None);
+    }
+
+    #[test]
+    fn rfc_without_repository() {
+        let html = r#"<html><head>
+            <meta property="og:site_name" content="GitHub">
+            <meta property="og:description" content="RFCs for changes to Rust">
+        </head><body>
+            <a href="/rust-lang/rfcs/blob/master/LICENSE-MIT">MIT license</a>
+        </body></html>"#;
+        let dom = Dom::parse(
+            html,
+            "https://github.com/rust-lang/rfcs/blob/master/text/2094-nll.md",
+        )
+        .unwrap();
+        let entry = CitationBuilder::new(dom)
+            .with_software_repository()
+            .unwrap()
+            .with_issue()
+            .unwrap()
+            .entry;
+
+        assert_eq!(entry.entry_type, EntryType::Misc);
+        assert_eq!(
+            entry.serial_number.first.as_deref(),
+            Some("rust-lang/rfcs#2094")
+        );
+        assert_eq!(entry.license.first, None);
+        assert_eq!(entry.note.first, None);
+    }
+
+    #[test]
+    fn exhibition_as_main_entity() {
+        let html = r#"<html><head><script type="application/ld+json">{
+            "@type": "WebPage",
+            "mainEntity": {
+                "@type": "ExhibitionEvent",
+                "name": "Electric Dreams",
+                "startDate": "2024-02-28",
+                "endDate": "2024-06-02",
+                "url": "https://tickets.example.org/electric-dreams",
+                "location": {"@type": "Place", "name": "Tate Modern", "address": {"addressLocality": "London"}}
+            }
+        }</script></head></html>"#;
+        let dom = Dom::parse(html, "https://www.tate.org.uk/whats-on/electric-dreams").unwrap();
+        let entry = CitationBuilder::new(dom)
+            .with_schema_metadata()
+            .unwrap()
+            .entry;
+
+        assert_eq!(entry.entry_type, EntryType::Exhibition);
+        assert_eq!(entry.location.first.as_deref(), Some("Tate Modern, London"));
+        assert_eq!(entry.note.first.as_deref(), Some("2024-02-28 – 2024-06-02"));
+        // the page is cited, not the ticket shop of the event
+        assert_eq!(
+            entry.url.first.map(String::from).as_deref(),
+            Some("https://www.tate.org.uk/whats-on/electric-dreams")
+        );
+    }
+
+    #[test]
+    fn event_teased_by_article() {
+        let html = r#"<html><head><script type="application/ld+json">[
+            {"@type": "Article", "headline": "What to see this spring"},
+            {
+                "@type": "TheaterEvent",
+                "name": "Hamlet",
+                "url": "https://theatre.example.org/hamlet"
+            }
+        ]</script></head></html>"#;
+        let dom = Dom::parse(html, "https://news.example.org/spring-guide").unwrap();
+        let entry = CitationBuilder::new(dom)
+            .with_schema_metadata()
+            .unwrap()
+            .entry;
+
+        assert_ne!(entry.entry_type, EntryType::Exhibition);
+        assert_eq!(entry.location.first, None);
+    }
+
+    #[test]
+    fn docs_chapter_of_book() {
+        let html = r#"<html><head><title>Data Types - The Rust Programming Language</title></head>
+        <body>
+            <nav id="sidebar"><ol class="chapter"><li>
+                <a class="active" href="ch03-02-data-types.html"><strong>3.2.</strong> Data Types</a>
+            </li></ol></nav>
+            <h1 class="menu-title">The Rust Programming Language</h1>
+            <main><h1>Data Types</h1></main>
+        </body></html>"#;
+        let dom = Dom::parse(
+            html,
+            "https://doc.rust-lang.org/book/ch03-02-data-types.html",
+        )
+        .unwrap();
+        let entry = CitationBuilder::new(dom).with_docs_chapter().unwrap().entry;
+
+        assert_eq!(entry.entry_type, EntryType::Chapter);
+        assert_eq!(entry.serial_number.first.as_deref(), Some("3.2"));
+
+        let book = entry.parent.unwrap();
+        assert_eq!(book.entry_type, EntryType::Book);
+        assert_eq!(
+            book.title.highest().map(|title| title.canonical),
+            Some("The Rust Programming Language".to_owned())
+        );
+    }
+
+    #[test]
+    fn social_post_thread() {
+        let html = r#"<html><head>
+            <meta property="profile:username" content="alice@mastodon.social">
+        </head><body>
+            <div class="status">
+                <a href="https://other.example/@bob/1"><time datetime="2024-03-01T10:00:00Z"></time></a>
+                <span class="display-name__html">Bob</span>
+                <div class="status__content">Should async traits be stable yet?</div>
+            </div>
+            <div class="status">
+                <a href="/@alice/2"><time datetime="2024-03-01T11:00:00Z"></time></a>
+                <span class="display-name__html">Alice</span>
+                <div class="status__content">They are, since 1.75.</div>
+            </div>
+            <div class="detailed-status">
+                <a href="/@alice/3"><time datetime="2024-03-01T12:00:00Z"></time></a>
+                <span class="display-name__html">Alice</span>
+                <div class="status__content">Agreed, and the docs could say so.</div>
+            </div>
+        </body></html>"#;
+        let dom = Dom::parse(html, "https://mastodon.social/@alice/3").unwrap();
+        let entry = CitationBuilder::new(dom).with_social_post().unwrap().entry;
+        let title =
+            |entry: &EntryBuilder| entry.title.clone().highest().map(|title| title.canonical);
+
+        assert_eq!(entry.entry_type, EntryType::Tweet);
+        assert_eq!(
+            title(&entry).as_deref(),
+            Some("Agreed, and the docs could say so.")
+        );
+
+        // the reply the post answers, then the post that started the thread
+        let reply = entry.parent.unwrap();
+        assert_eq!(reply.entry_type, EntryType::Thread);
+        assert_eq!(title(&reply).as_deref(), Some("They are, since 1.75."));
+        assert_eq!(
+            reply.url.first.as_ref().map(Url::as_str),
+            Some("https://mastodon.social/@alice/2")
+        );
+
+        let root = reply.parent.unwrap();
+        assert_eq!(
+            title(&root).as_deref(),
+            Some("Should async traits be stable yet?")
+        );
+        assert_eq!(root.parent, None);
+    }
+
+    #[test]
+    fn rfc_with_highwire_number() {
+        let html = r#"<html><head>
+            <meta name="citation_title" content="HTTP Semantics">
+            <meta name="citation_technical_report_institution" content="Internet Engineering Task Force">
+            <meta name="citation_technical_report_number" content="rfc9110">
+            <meta name="citation_publication_date" content="2022-06">
+        </head><body>
+            <h1 id="title">HTTP Semantics</h1>
+            <div class="author-name">R. Fielding, Ed.</div>
+        </body></html>"#;
+        let dom = Dom::parse(html, "https://datatracker.ietf.org/doc/html/rfc9110").unwrap();
+        let entry = CitationBuilder::from_dom(dom).unwrap().entry;
+
+        assert_eq!(entry.entry_type, EntryType::Report);
+        assert_eq!(entry.serial_number.highest().as_deref(), Some("RFC 9110"));
+        assert_eq!(entry.organization.highest().as_deref(), Some("IETF"));
+    }
+
+    #[test]
+    fn commons_file_page() {
+        let html = r#"<html><head>
+            <script>RLCONF={"wgNamespaceNumber":6,"wgTitle":"The Night Watch.jpg",
+                "wgPageName":"File:The_Night_Watch.jpg","wgRevisionId":5555,
+                "wgSiteName":"Wikimedia Commons","wgContentLanguage":"en"};</script>
+        </head><body>
+            <h1 id="firstHeading">File:The Night Watch.jpg</h1>
+            <table><tr>
+                <td id="fileinfotpl_creator">Artist</td>
+                <td><span class="fn">Rembrandt</span></td>
+            </tr><tr>
+                <td id="fileinfotpl_art_title">Title</td>
+                <td>The Night Watch</td>
+            </tr><tr>
+                <td id="fileinfotpl_art_gallery">Collection</td>
+                <td><a href="/wiki/Rijksmuseum">Rijksmuseum</a></td>
+            </tr><tr>
+                <td id="fileinfotpl_art_id">Accession number</td>
+                <td>SK-C-5</td>
+            </tr></table>
+        </body></html>"#;
+        let dom = Dom::parse(
+            html,
+            "https://commons.wikimedia.org/wiki/File:The_Night_Watch.jpg",
+        )
+        .unwrap();
+        let entry = CitationBuilder::from_dom(dom).unwrap().entry;
+
+        assert_eq!(entry.entry_type, EntryType::Artwork);
+        assert_eq!(
+            entry.title.clone().highest().map(|title| title.canonical),
+            Some("The Night Watch".to_owned())
+        );
+        assert_eq!(entry.serial_number.highest().as_deref(), Some("SK-C-5"));
+        assert_eq!(entry.organization.highest().as_deref(), Some("Rijksmuseum"));
+        assert_eq!(entry.parent, None);
     }
-    key.pop();
-    key
 }
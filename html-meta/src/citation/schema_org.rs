@@ -0,0 +1,470 @@
+//! Maps the schema.org objects of a page onto the entry of its citation.
+
+use crate::{
+    entry::EntryBuilder,
+    meta::{
+        schema::{SchemaMetadata, SchemaObject},
+        value::{Doi, PageRange, PartialDate},
+    },
+    site::artwork::creation_date,
+};
+use hayagriva::types::{EntryType, PersonRole};
+use url::Url;
+
+pub const NEWS_ARTICLE_TYPES: &[&str] = &[
+    "NewsArticle",
+    "AnalysisNewsArticle",
+    "AskPublicNewsArticle",
+    "BackgroundNewsArticle",
+    "OpinionNewsArticle",
+    "ReportageNewsArticle",
+    "ReviewNewsArticle",
+];
+
+pub const ARTWORK_TYPES: &[&str] = &[
+    "VisualArtwork",
+    "Painting",
+    "Sculpture",
+    "Drawing",
+    "Photograph",
+];
+
+// the events hayagriva cites as `Exhibition`, opposed to e.g. concerts or conferences
+pub const EVENT_TYPES: &[&str] = &["ExhibitionEvent", "TheaterEvent"];
+
+/// Applies the properties every creative work shares, like its name and authors.
+pub fn apply_work(entry: &mut EntryBuilder, work: &SchemaObject) {
+    if let Some(title) = work.text("headline").or(work.text("name")) {
+        entry.title.first = Some(title.into());
+    }
+
+    let authors = work.names("author");
+    if !authors.is_empty() {
+        entry.authors.first = Some(authors);
+    }
+
+    if let Some(date) = work.date("datePublished") {
+        entry.date.first = Some(date);
+    }
+}
+
+/// A `ScholarlyArticle`, which may have been presented at an `Event`
+/// or published as part of the proceedings of a conference.
+pub fn apply_paper(entry: &mut EntryBuilder, schema: &SchemaMetadata, paper: &SchemaObject) {
+    apply_work(entry, paper);
+
+    if let Some(pages) = page_range(paper) {
+        entry.page_range.first = Some(pages);
+    }
+
+    let name = paper.text("headline").or(paper.text("name"));
+    let proceedings = paper.objects("isPartOf").into_iter().find(is_proceedings);
+    let event = paper.object("recordedAt").or_else(|| {
+        let presents = |event: &&SchemaObject| {
+            let works = [event.texts("workPresented"), event.texts("workFeatured")];
+            name.as_ref()
+                .is_some_and(|name| works.iter().flatten().any(|work| work == name))
+        };
+        schema.find(&["Event"]).filter(presents).cloned()
+    });
+
+    if proceedings.is_none() && event.is_none() {
+        return;
+    }
+
+    entry.entry_type = EntryType::Article;
+
+    let year = entry.date.first.map(PartialDate::year_only);
+    let parent = entry.parent_mut(EntryType::Proceedings);
+
+    if let Some(proceedings) = proceedings {
+        if let Some(title) = proceedings.text("name") {
+            parent.title.first = Some(title.into());
+        }
+
+        let editors = proceedings.names("editor");
+        if !editors.is_empty() {
+            parent.editors.first = Some(editors);
+        }
+
+        if let Some(publisher) = proceedings.text("publisher") {
+            parent.publisher.first = Some(publisher);
+        }
+
+        if let Some(date) = proceedings.date("datePublished") {
+            parent.date.first = Some(date.year_only());
+        }
+    }
+
+    if let Some(event) = event {
+        if let Some(title) = event.text("name") {
+            parent.title.second = Some(title.into());
+        }
+
+        if let Some(location) = event.place("location") {
+            parent.location.first = Some(location);
+        }
+    }
+
+    if let Some(publisher) = paper.text("publisher") {
+        parent.publisher.second = Some(publisher);
+    }
+
+    if let Some(year) = year {
+        parent.date.second = Some(year);
+    }
+}
+
+/// A `NewsArticle` published in a newspaper, optionally also in print.
+pub fn apply_news_article(entry: &mut EntryBuilder, article: &SchemaObject) {
+    apply_work(entry, article);
+
+    entry.entry_type = EntryType::Article;
+
+    if let Some(section) = article.text("articleSection") {
+        entry.note.first = Some(section);
+    }
+
+    if let Some(edition) = article.text("printEdition") {
+        entry.edition.first = Some(edition);
+    }
+
+    if let Some(pages) = article.text("printPage").and_then(|page| page.parse().ok()) {
+        entry.page_range.first = Some(pages);
+    }
+
+    let newspaper = entry.parent_mut(EntryType::Newspaper);
+
+    if let Some(title) = article.text("isPartOf") {
+        newspaper.title.first = Some(title.into());
+    }
+
+    if let Some(title) = article.text("publisher") {
+        newspaper.title.second = Some(title.into());
+    }
+}
+
+/// A `Dataset`, which hayagriva has no entry type for and is thus cited as `Misc`.
+pub fn apply_dataset(entry: &mut EntryBuilder, dataset: &SchemaObject) {
+    apply_work(entry, dataset);
+
+    entry.entry_type = EntryType::Misc;
+
+    let creators = dataset.names("creator");
+    if !creators.is_empty() {
+        entry.authors.first = Some(creators);
+    }
+
+    if entry.date.first.is_none()
+        && let Some(date) = dataset.date("dateCreated")
+    {
+        entry.date.first = Some(date);
+    }
+
+    // the repository the dataset was deposited in
+    if let Some(publisher) = dataset
+        .text("publisher")
+        .or(dataset.text("includedInDataCatalog"))
+    {
+        entry.publisher.first = Some(publisher);
+    }
+
+    if let Some(version) = dataset.text("version") {
+        entry.serial_number.first = Some(version);
+    }
+
+    let doi = [dataset.texts("identifier"), dataset.texts("@id")]
+        .into_iter()
+        .flatten()
+        .find_map(|identifier| identifier.parse::<Doi>().ok());
+    if let Some(doi) = doi {
+        entry.doi.first = Some(doi.into());
+    }
+
+    if let Some(license) = dataset.text("license") {
+        entry.license.first = Some(license);
+    }
+
+    if let Some(url) = dataset.url("url") {
+        entry.url.first = Some(url);
+    }
+}
+
+/// A `SoftwareSourceCode`, e.g. described by the `codemeta.json` file of a repository.
+pub fn apply_software(entry: &mut EntryBuilder, software: &SchemaObject) {
+    apply_work(entry, software);
+
+    entry.entry_type = EntryType::Repository;
+
+    if let Some(version) = software
+        .text("softwareVersion")
+        .or(software.text("version"))
+    {
+        entry.serial_number.first = Some(version);
+    }
+
+    let doi = software
+        .texts("identifier")
+        .into_iter()
+        .find_map(|identifier| identifier.parse::<Doi>().ok());
+    if let Some(doi) = doi {
+        entry.doi.first = Some(doi.into());
+    }
+
+    // codemeta links the license as e.g. "https://spdx.org/licenses/MIT"
+    if let Some(license) = software.text("license") {
+        let license = license
+            .strip_prefix("https://spdx.org/licenses/")
+            .map(ToOwned::to_owned)
+            .unwrap_or(license);
+        entry.license.first = Some(license);
+    }
+
+    if let Some(url) = software.url("codeRepository").or(software.url("url")) {
+        entry.url.first = Some(url);
+    }
+}
+
+/// A `VisualArtwork` of a museum, with its material and dimensions as note.
+pub fn apply_artwork(entry: &mut EntryBuilder, artwork: &SchemaObject) {
+    apply_work(entry, artwork);
+
+    entry.entry_type = EntryType::Artwork;
+
+    let artists = [artwork.names("artist"), artwork.names("creator")]
+        .into_iter()
+        .find(|artists| !artists.is_empty());
+    if let Some(artists) = artists {
+        entry.authors.first = Some(artists);
+    }
+
+    if let Some(date) = artwork
+        .text("dateCreated")
+        .and_then(|date| creation_date(&date))
+    {
+        entry.date.first = Some(date);
+    }
+
+    let medium = [artwork.texts("artMedium"), artwork.texts("material")]
+        .into_iter()
+        .find(|medium| !medium.is_empty())
+        .map(|medium| medium.join(", "));
+    // e.g. "73.5 cm" or a QuantitativeValue like {"value": 73.5, "unitText": "cm"}
+    let dimensions = ["height", "width", "depth"]
+        .into_iter()
+        .filter_map(|key| match artwork.object(key) {
+            Some(value) => {
+                let number = value.text("value")?;
+                let unit = value.text("unitText").or(value.text("unitCode"));
+                Some(unit.map_or(number.clone(), |unit| format!("{number} {unit}")))
+            }
+            None => artwork.text(key),
+        })
+        .collect::<Vec<_>>();
+    let dimensions = Some(dimensions.join(" × ")).filter(|dimensions| !dimensions.is_empty());
+
+    let note = [medium, dimensions]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+    if !note.is_empty() {
+        entry.note.first = Some(note.join(", "));
+    }
+
+    if let Some(institution) = [
+        "holdingArchive",
+        "provider",
+        "sourceOrganization",
+        "publisher",
+    ]
+    .into_iter()
+    .find_map(|key| artwork.text(key))
+    {
+        entry.organization.first = Some(institution);
+    }
+
+    // the accession number, opposed to identifiers like the url of the object
+    if let Some(number) = artwork
+        .texts("identifier")
+        .into_iter()
+        .find(|identifier| !identifier.starts_with("http"))
+    {
+        entry.serial_number.first = Some(number);
+    }
+
+    if let Some(url) = artwork.url("url") {
+        entry.url.first = Some(url);
+    }
+}
+
+/// An `ArchiveComponent` like the records ArchivesSpace publishes of its collections and items.
+pub fn apply_archive_component(entry: &mut EntryBuilder, component: &SchemaObject) {
+    apply_work(entry, component);
+
+    entry.entry_type = EntryType::Manuscript;
+
+    let creators = component.names("creator");
+    if !creators.is_empty() {
+        entry.authors.first = Some(creators);
+    }
+
+    if let Some(date) = component
+        .text("dateCreated")
+        .and_then(|date| creation_date(&date))
+    {
+        entry.date.first = Some(date);
+    }
+
+    if let Some(archive) = component.text("holdingArchive") {
+        entry.archive.first = Some(archive);
+    }
+
+    // e.g. "Papers of Jane Doe, MS 408" of the collection and the call number
+    let collection = component.text("isPartOf");
+    let identifier = component
+        .texts("identifier")
+        .into_iter()
+        .find(|identifier| !identifier.starts_with("http"));
+    let location = [collection, identifier]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+    if !location.is_empty() {
+        entry.archive_location.first = Some(location.join(", "));
+    }
+
+    if let Some(url) = component.url("url") {
+        entry.url.first = Some(url);
+    }
+}
+
+/// An exhibition or a performance, which hayagriva both cites as `Exhibition`.
+pub fn apply_event(entry: &mut EntryBuilder, event: &SchemaObject) {
+    entry.entry_type = EntryType::Exhibition;
+
+    if let Some(title) = event.text("name") {
+        entry.title.first = Some(title.into());
+    }
+
+    if let Some(date) = event.date("startDate") {
+        entry.date.first = Some(date);
+    }
+
+    // hayagriva has no date range, e.g. "2024-01-10 – 2024-03-12" of an exhibition
+    let day = |key| {
+        event
+            .text(key)
+            .and_then(|date| date.split('T').next().map(ToOwned::to_owned))
+    };
+    if let (Some(start), Some(end)) = (day("startDate"), day("endDate"))
+        && start != end
+    {
+        entry.note.first = Some(format!("{start} – {end}"));
+    }
+
+    if let Some(venue) = venue(event) {
+        entry.location.first = Some(venue);
+    }
+
+    let organizers = event.names("organizer");
+    if let Some(organizer) = event.text("organizer") {
+        entry.organization.first = Some(organizer);
+    }
+
+    let affiliated_persons = [
+        (organizers, PersonRole::Organizer),
+        (event.names("performer"), PersonRole::CastMember),
+        (event.names("director"), PersonRole::Director),
+        (event.names("composer"), PersonRole::Composer),
+    ]
+    .into_iter()
+    .filter(|(names, _)| !names.is_empty())
+    .collect::<Vec<_>>();
+    if !affiliated_persons.is_empty() {
+        entry.affiliated_persons.first = Some(affiliated_persons);
+    }
+}
+
+/// The event a paper was presented at, linked by its `recordedAt` or `isPartOf`
+/// either as a nested object or by its `@id`, opposed to e.g. other events announced on the page.
+pub fn paper_event(schema: &SchemaMetadata) -> Option<SchemaObject> {
+    schema
+        .objects
+        .iter()
+        .flat_map(|object| [object.objects("recordedAt"), object.objects("isPartOf")].concat())
+        .find_map(|linked| {
+            if linked.is_any(&["Event"]) {
+                return Some(linked);
+            }
+
+            let id = linked.text("@id")?;
+            schema
+                .objects
+                .iter()
+                .find(|object| {
+                    object.is_any(&["Event"]) && object.text("@id").as_deref() == Some(&id)
+                })
+                .cloned()
+        })
+}
+
+/// The event the page is about, either as the `mainEntity` of the page or by its url,
+/// opposed to e.g. the events listed on a calendar or teased by an article.
+pub fn main_event(schema: &SchemaMetadata, page: &Url) -> Option<SchemaObject> {
+    let main_entity = schema
+        .objects
+        .iter()
+        .filter_map(|object| object.object("mainEntity"))
+        .find(|object| object.is_any(EVENT_TYPES));
+    if main_entity.is_some() {
+        return main_entity;
+    }
+
+    let comparable = |url: &Url| {
+        let host = url
+            .host_str()
+            .unwrap_or_default()
+            .trim_start_matches("www.");
+        format!("{host}{}", url.path().trim_end_matches('/'))
+    };
+    schema
+        .objects
+        .iter()
+        .filter(|object| object.is_any(EVENT_TYPES))
+        .find(|event| {
+            event
+                .url("url")
+                .is_some_and(|url| comparable(&url) == comparable(page))
+        })
+        .cloned()
+}
+
+// e.g. "Tate Modern, London" of a `Place` with a name and an address
+fn venue(event: &SchemaObject) -> Option<String> {
+    let place = event.place("location")?;
+
+    match event
+        .object("location")
+        .and_then(|location| location.text("name"))
+    {
+        Some(name) if !place.contains(&name) => Some(format!("{name}, {place}")),
+        _ => Some(place),
+    }
+}
+
+fn page_range(work: &SchemaObject) -> Option<PageRange> {
+    if let Some(first) = work.text("pageStart") {
+        let last = work.text("pageEnd");
+        PageRange::new(&first, last.as_deref()).ok()
+    } else {
+        work.text("pagination")?.parse().ok()
+    }
+}
+
+fn is_proceedings(work: &SchemaObject) -> bool {
+    let name = work.text("name").unwrap_or_default().to_lowercase();
+
+    ["proceedings", "conference", "symposium", "workshop"]
+        .iter()
+        .any(|word| name.contains(word))
+}
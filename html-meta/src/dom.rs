@@ -1,6 +1,9 @@
 use crate::{
     error::MetaResult,
-    meta::{generic::GenericMetadata, ogp::OgpMetadata},
+    meta::{
+        dublin_core::DublinCoreMetadata, generic::GenericMetadata, highwire::HighwireMetadata,
+        ogp::OgpMetadata, schema::SchemaMetadata,
+    },
     query::HtmlQueryReport,
 };
 use scraper::{Html, Selector};
@@ -39,6 +42,27 @@ impl Dom {
         Ok(OgpMetadata::extract(select))
     }
 
+    pub fn highwire_metadata(&self) -> MetaResult<HighwireMetadata> {
+        let selector = Selector::parse("meta")?;
+        let select = self.html.select(&selector);
+
+        Ok(HighwireMetadata::extract(select))
+    }
+
+    pub fn dublin_core_metadata(&self) -> MetaResult<DublinCoreMetadata> {
+        let selector = Selector::parse("meta")?;
+        let select = self.html.select(&selector);
+
+        Ok(DublinCoreMetadata::extract(select))
+    }
+
+    pub fn schema_metadata(&self) -> MetaResult<SchemaMetadata> {
+        let selector = Selector::parse("script[type=\"application/ld+json\"]")?;
+        let select = self.html.select(&selector);
+
+        Ok(SchemaMetadata::extract(select))
+    }
+
     pub fn html_query_report(&self) -> MetaResult<HtmlQueryReport> {
         let report = HtmlQueryReport::from(&self.html)?;
         Ok(report)
@@ -47,4 +71,41 @@ impl Dom {
     pub fn url(&self) -> &Url {
         &self.url
     }
+
+    pub fn html(&self) -> &Html {
+        &self.html
+    }
+
+    /// The trimmed text of the first element matching the selector which has any text.
+    pub fn text(&self, selector: &'static str) -> MetaResult<Option<String>> {
+        let selector = Selector::parse(selector)?;
+
+        let text = self
+            .html
+            .select(&selector)
+            .map(|el| el.text().collect::<String>().trim().to_owned())
+            .find(|text| !text.is_empty());
+
+        Ok(text)
+    }
+
+    /// The trimmed content attribute of the first element matching the selector.
+    pub fn meta_content(&self, selector: &'static str) -> MetaResult<Option<String>> {
+        self.attr(selector, "content")
+    }
+
+    /// The trimmed attribute of the first element matching the selector which has it.
+    pub fn attr(&self, selector: &'static str, attr: &str) -> MetaResult<Option<String>> {
+        let selector = Selector::parse(selector)?;
+
+        let value = self
+            .html
+            .select(&selector)
+            .filter_map(|el| el.value().attr(attr))
+            .map(str::trim)
+            .find(|value| !value.is_empty())
+            .map(ToOwned::to_owned);
+
+        Ok(value)
+    }
 }
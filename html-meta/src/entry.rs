@@ -0,0 +1,304 @@
+use crate::{
+    meta::value::{into_qualified, Name, PageRange, PartialDate, Runtime, Title},
+    priority::PriorityData,
+};
+use hayagriva::{
+    types::{EntryType, NumOrStr, PersonRole},
+    Entry,
+};
+use isbn2::Isbn;
+use unic_langid::LanguageIdentifier;
+use url::Url;
+
+const PLACEHOLDER: &'static str = "placeholder";
+
+/// Collects the data of a single hayagriva entry from multiple sources.
+/// An entry may be contained in a parent entry, e.g. a paper in the proceedings of a conference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryBuilder {
+    pub entry_type: EntryType,
+    pub title: PriorityData<Title>,
+    pub authors: PriorityData<Vec<Name>>,
+    pub date: PriorityData<PartialDate>,
+    pub editors: PriorityData<Vec<Name>>,
+    /// The persons involved in other roles than author or editor, e.g. the performers of a play.
+    pub affiliated_persons: PriorityData<Vec<(Vec<Name>, PersonRole)>>,
+    pub publisher: PriorityData<String>,
+    pub location: PriorityData<String>,
+    pub organization: PriorityData<String>,
+    /// The number of the issue or the episode, e.g. of a podcast.
+    pub issue: PriorityData<String>,
+    /// The part of a multi-part item this entry is, e.g. of a series of blog posts.
+    pub volume: PriorityData<i64>,
+    /// The number of volumes of a multi-volume work, e.g. of a book set.
+    pub volume_total: PriorityData<i64>,
+    /// The published version, e.g. "2" or "Late Edition".
+    pub edition: PriorityData<String>,
+    pub page_range: PriorityData<PageRange>,
+    pub page_total: PriorityData<i64>,
+    /// The playing time of a video or an audio recording.
+    pub runtime: PriorityData<Runtime>,
+    pub url: PriorityData<Url>,
+    pub doi: PriorityData<String>,
+    pub serial_number: PriorityData<String>,
+    pub isbn: PriorityData<Isbn>,
+    pub language: PriorityData<LanguageIdentifier>,
+    /// The archive holding the item, e.g. the library of a manuscript.
+    pub archive: PriorityData<String>,
+    /// Where the item is kept within its archive, e.g. its collection and call number.
+    pub archive_location: PriorityData<String>,
+    /// Additional information, e.g. the section of a newspaper article.
+    pub note: PriorityData<String>,
+    /// The license of the item, which hayagriva has no field for and is thus appended to the note.
+    pub license: PriorityData<String>,
+    pub parent: Option<Box<EntryBuilder>>,
+}
+
+impl EntryBuilder {
+    pub fn new(entry_type: EntryType) -> Self {
+        Self {
+            entry_type,
+            title: Default::default(),
+            authors: Default::default(),
+            date: Default::default(),
+            editors: Default::default(),
+            affiliated_persons: Default::default(),
+            publisher: Default::default(),
+            location: Default::default(),
+            organization: Default::default(),
+            issue: Default::default(),
+            volume: Default::default(),
+            volume_total: Default::default(),
+            edition: Default::default(),
+            page_range: Default::default(),
+            page_total: Default::default(),
+            runtime: Default::default(),
+            url: Default::default(),
+            doi: Default::default(),
+            serial_number: Default::default(),
+            isbn: Default::default(),
+            language: Default::default(),
+            archive: Default::default(),
+            archive_location: Default::default(),
+            note: Default::default(),
+            license: Default::default(),
+            parent: None,
+        }
+    }
+
+    /// Returns the parent of this entry, creating it if there is none yet.
+    /// A parent of another type is replaced, so e.g. the owner of a blog
+    /// does not become the author of the newspaper replacing it.
+    pub fn parent_mut(&mut self, entry_type: EntryType) -> &mut EntryBuilder {
+        let parent = self
+            .parent
+            .take()
+            .filter(|parent| parent.entry_type == entry_type)
+            .unwrap_or_else(|| Box::new(EntryBuilder::new(entry_type)));
+
+        self.parent.insert(parent)
+    }
+}
+
+impl From<EntryBuilder> for Entry {
+    fn from(builder: EntryBuilder) -> Self {
+        let EntryBuilder {
+            entry_type,
+            title,
+            authors,
+            date,
+            editors,
+            affiliated_persons,
+            publisher,
+            location,
+            organization,
+            issue,
+            volume,
+            volume_total,
+            edition,
+            page_range,
+            page_total,
+            runtime,
+            url,
+            doi,
+            serial_number,
+            isbn,
+            language,
+            archive,
+            archive_location,
+            note,
+            license,
+            parent,
+        } = builder;
+
+        let highest_title = title.highest();
+
+        let key = highest_title
+            .as_ref()
+            .map(calculate_key)
+            .unwrap_or(PLACEHOLDER.to_owned());
+
+        let mut entry = Entry::new(&key, entry_type);
+
+        if let Some(title) = highest_title {
+            entry.set_title(title.into());
+        }
+
+        if let Some(authors) = authors.highest() {
+            let item = authors.into_iter().map(Into::into).collect();
+            entry.set_authors(item);
+        }
+
+        if let Some(date) = date.highest() {
+            entry.set_date(date.into());
+        }
+
+        if let Some(editors) = editors.highest() {
+            let item = editors.into_iter().map(Into::into).collect();
+            entry.set_editors(item);
+        }
+
+        if let Some(affiliated_persons) = affiliated_persons.highest() {
+            let item = affiliated_persons
+                .into_iter()
+                .map(|(names, role)| (names.into_iter().map(Into::into).collect(), role))
+                .collect();
+            entry.set_affiliated_persons(item);
+        }
+
+        if let Some(publisher) = publisher.highest() {
+            entry.set_publisher(publisher.into());
+        }
+        if let Some(location) = location.highest() {
+            entry.set_location(location.into());
+        }
+
+        if let Some(orga) = organization.highest() {
+            entry.set_organization(orga);
+        }
+
+        if let Some(issue) = issue.highest() {
+            entry.set_issue(num_or_str(issue));
+        }
+
+        if let Some(volume) = volume.highest() {
+            entry.set_volume(volume..volume);
+        }
+
+        if let Some(volume_total) = volume_total.highest() {
+            entry.set_volume_total(volume_total);
+        }
+
+        if let Some(edition) = edition.highest() {
+            entry.set_edition(num_or_str(edition));
+        }
+
+        if let Some(page_range) = page_range.highest() {
+            entry.set_page_range(page_range.into());
+        }
+
+        if let Some(page_total) = page_total.highest() {
+            entry.set_page_total(page_total);
+        }
+
+        if let Some(runtime) = runtime.highest()
+            && let Ok(runtime) = runtime.to_string().parse()
+        {
+            entry.set_runtime(runtime);
+        }
+
+        if let Some(url) = url.highest() {
+            entry.set_url(into_qualified(url));
+        }
+
+        if let Some(doi) = doi.highest() {
+            entry.set_doi(doi);
+        }
+        if let Some(serial_number) = serial_number.highest() {
+            entry.set_serial_number(serial_number);
+        }
+        if let Some(isbn) = isbn.highest() {
+            entry.set_isbn(isbn.to_string());
+        }
+
+        if let Some(language) = language.highest() {
+            entry.set_language(language);
+        }
+
+        if let Some(archive) = archive.highest() {
+            entry.set_archive(archive.into());
+        }
+        if let Some(archive_location) = archive_location.highest() {
+            entry.set_archive_location(archive_location.into());
+        }
+
+        let note = match (note.highest(), license.highest()) {
+            (Some(note), Some(license)) => Some(format!("{note}, License: {license}")),
+            (None, Some(license)) => Some(format!("License: {license}")),
+            (note, None) => note,
+        };
+        if let Some(note) = note {
+            entry.set_note(note);
+        }
+
+        if let Some(parent) = parent {
+            entry.set_parents(vec![Entry::from(*parent)]);
+        }
+
+        entry
+    }
+}
+
+fn num_or_str(value: String) -> NumOrStr {
+    match value.parse::<i64>() {
+        Ok(number) => NumOrStr::Number(number),
+        Err(_) => NumOrStr::Str(value),
+    }
+}
+
+// TODO remove special characters
+fn calculate_key(title: &Title) -> String {
+    // TODO add author to key if found
+    let key = title
+        .canonical
+        .to_ascii_lowercase()
+        .replace(|c: char| !c.is_ascii(), "");
+    let mut splitted_key = key.trim().split_whitespace().enumerate();
+
+    let mut key = String::new();
+    while let Some((counter, part)) = splitted_key.next() {
+        key.push_str(part);
+        key.push('-');
+
+        if counter == 3 {
+            break;
+        }
+    }
+    key.pop();
+    key
+}
+
+#[cfg(test)]
+mod test {
+    use super::EntryBuilder;
+    use crate::meta::value::Name;
+    use hayagriva::types::EntryType;
+
+    #[test]
+    fn parent_of_other_type() {
+        let mut entry = EntryBuilder::new(EntryType::Article);
+
+        let blog = entry.parent_mut(EntryType::Blog);
+        blog.title.first = Some("Jane's Blog".to_owned().into());
+        blog.authors.second = Some(vec![Name::literal("Jane Doe")]);
+
+        // the same type keeps the data of the parent
+        let blog = entry.parent_mut(EntryType::Blog);
+        assert!(blog.title.first.is_some());
+
+        let newspaper = entry.parent_mut(EntryType::Newspaper);
+        assert_eq!(newspaper.entry_type, EntryType::Newspaper);
+        assert_eq!(newspaper.title.first, None);
+        assert_eq!(newspaper.authors.second, None);
+    }
+}
@@ -10,20 +10,32 @@ use wasm_bindgen::prelude::*;
 pub enum MetaError {
     #[error("Selector Error: {0}")]
     Selector(#[from] SelectorErrorKind<'static>),
+    #[error("Invalid selector: {0}")]
+    InvalidSelector(String),
+    #[error("Element not found")]
+    ElementNotFound,
     #[error("Yaml Parse Error")]
     YamlParse,
     #[error("Int Parse Error: {0}")]
     IntParse(#[from] ParseIntError),
     #[error("Date Parse Error: {0}")]
     DateParse(#[from] chrono::ParseError),
+    #[error("Cannot parse partial date")]
+    PartialDateParse,
     #[error("Url Parse Error: {0}")]
     UrlParse(#[from] url::ParseError),
     #[error("Isbn Error: {0}")]
     Isbn(IsbnError),
+    #[error("Cannot parse doi")]
+    DoiParse,
     #[error("Cannot parse issn")]
     IssnParse,
     #[error("Language Identifier Error: {0}")]
     LanguageIdentifier(#[from] LanguageIdentifierError),
+    #[error("Cannot parse page range")]
+    PageRangeParse,
+    #[error("Cannot parse runtime")]
+    RuntimeParse,
     #[error("Cannot parse name")]
     NameParse,
     #[error("Title not Found")]
@@ -38,6 +50,12 @@ pub enum MetaError {
     WrongMetadataType,
     #[error("Missing metadata")]
     MissingMetadata,
+    #[error("Xml Parse Error: {0}")]
+    XmlParse(#[from] roxmltree::Error),
+    #[error("Episode not found in feed")]
+    EpisodeNotFound,
+    #[error("Json Parse Error: {0}")]
+    JsonParse(#[from] serde_json::Error),
     #[error("Infallible")]
     Infallible(#[from] Infallible),
 }
@@ -0,0 +1,338 @@
+use crate::{
+    entry::EntryBuilder,
+    error::MetaResult,
+    meta::value::{Name, PartialDate, Runtime},
+    Citation,
+};
+use hayagriva::{types::EntryType, Entry};
+use roxmltree::{Document, Node};
+use unic_langid::LanguageIdentifier;
+use url::Url;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+const ATOM: &str = "http://www.w3.org/2005/Atom";
+const ITUNES: &str = "http://www.itunes.com/dtds/podcast-1.0.dtd";
+const PODCAST: &str = "https://podcastindex.org/namespace/1.0";
+const DUBLIN_CORE: &str = "http://purl.org/dc/elements/1.1/";
+
+/// An RSS 2.0 or Atom feed, e.g. of a podcast, whose episodes are described far better
+/// by the feed than by their web pages.
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Feed {
+    show: Show,
+    episodes: Vec<Episode>,
+}
+
+#[wasm_bindgen]
+impl Feed {
+    #[wasm_bindgen(constructor)]
+    pub fn parse(xml: &str) -> MetaResult<Feed> {
+        let document = Document::parse(xml)?;
+        let root = document.root_element();
+
+        // the elements of RSS have no namespace, the ones of Atom do
+        let (channel, namespace, item) = match root.tag_name().name() {
+            "feed" => (Some(root), Some(ATOM), "entry"),
+            _ => (child(root, None, "channel"), None, "item"),
+        };
+
+        let (show, episodes) = match channel {
+            Some(channel) => (
+                Show::parse(channel, namespace),
+                channel
+                    .children()
+                    .filter(|node| is(node, namespace, item))
+                    .map(|node| Episode::parse(node, namespace))
+                    .collect(),
+            ),
+            None => (Show::default(), Vec::new()),
+        };
+
+        Ok(Self { show, episodes })
+    }
+}
+
+impl Feed {
+    pub fn show(&self) -> &Show {
+        &self.show
+    }
+
+    pub fn episodes(&self) -> &[Episode] {
+        &self.episodes
+    }
+
+    /// The episode whose web page, guid or audio file is at the given url.
+    pub fn episode(&self, url: &Url) -> Option<&Episode> {
+        let url = comparable(url);
+        let matches =
+            |other: &Option<Url>| other.as_ref().is_some_and(|other| comparable(other) == url);
+
+        self.episodes.iter().find(|episode| {
+            let guid = episode.guid.as_deref().and_then(|guid| guid.parse().ok());
+            matches(&episode.url) || matches(&guid) || matches(&episode.enclosure)
+        })
+    }
+
+    /// The entry of an episode, with the show as its parent.
+    pub fn entry(&self, episode: &Episode) -> EntryBuilder {
+        let Show {
+            title,
+            hosts,
+            owner,
+            url,
+            language,
+        } = self.show.clone();
+        let mut entry = EntryBuilder::new(EntryType::Audio);
+
+        entry.title.first = episode.title.clone().map(Into::into);
+        entry.authors.first = Some(episode.hosts.clone())
+            .filter(|hosts| !hosts.is_empty())
+            .or(Some(hosts.clone()).filter(|hosts| !hosts.is_empty()));
+        entry.date.first = episode.published;
+        entry.runtime.first = episode.runtime;
+        entry.issue.first = episode.number.clone();
+        entry.volume.first = episode.season;
+        entry.url.first = episode.url.clone().or(episode.enclosure.clone());
+        entry.language.first = language.clone();
+
+        let show = entry.parent_mut(EntryType::Audio);
+        show.title.first = title.map(Into::into);
+        show.authors.first = Some(hosts).filter(|hosts| !hosts.is_empty());
+        show.publisher.first = owner;
+        show.url.first = url;
+        show.language.first = language;
+
+        entry
+    }
+
+    pub fn citations(&self) -> Vec<Citation> {
+        self.episodes
+            .iter()
+            .map(|episode| Entry::from(self.entry(episode)).into())
+            .collect()
+    }
+}
+
+/// The channel of an RSS feed or the feed element of an Atom feed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Show {
+    pub title: Option<String>,
+    pub hosts: Vec<Name>,
+    /// The owner of the podcast, e.g. a broadcaster.
+    pub owner: Option<String>,
+    /// The website of the show.
+    pub url: Option<Url>,
+    pub language: Option<LanguageIdentifier>,
+}
+
+impl Show {
+    fn parse(channel: Node, namespace: Option<&str>) -> Self {
+        let owner = child(channel, Some(ITUNES), "owner")
+            .and_then(|owner| text(owner, Some(ITUNES), "name"))
+            .or(text(channel, namespace, "copyright"));
+
+        Self {
+            title: text(channel, namespace, "title"),
+            hosts: hosts(channel, namespace),
+            owner,
+            url: link(channel, namespace, "alternate"),
+            language: text(channel, namespace, "language").and_then(|lang| lang.parse().ok()),
+        }
+    }
+}
+
+/// An item of an RSS feed or an entry of an Atom feed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Episode {
+    pub title: Option<String>,
+    pub hosts: Vec<Name>,
+    pub published: Option<PartialDate>,
+    pub runtime: Option<Runtime>,
+    /// The number of the episode, which may also be e.g. "12.5" for bonus episodes.
+    pub number: Option<String>,
+    pub season: Option<i64>,
+    /// The web page of the episode.
+    pub url: Option<Url>,
+    pub guid: Option<String>,
+    /// The audio file of the episode.
+    pub enclosure: Option<Url>,
+}
+
+impl Episode {
+    fn parse(item: Node, namespace: Option<&str>) -> Self {
+        let published = text(item, namespace, "pubDate")
+            .or(text(item, namespace, "published"))
+            .or(text(item, namespace, "updated"))
+            .and_then(|date| date.parse().ok());
+        let enclosure = child(item, namespace, "enclosure")
+            .and_then(|enclosure| enclosure.attribute("url"))
+            .and_then(|url| url.parse().ok())
+            .or(link(item, namespace, "enclosure"));
+
+        Self {
+            title: text(item, namespace, "title"),
+            hosts: hosts(item, namespace),
+            published,
+            runtime: text(item, Some(ITUNES), "duration").and_then(|runtime| runtime.parse().ok()),
+            number: text(item, Some(ITUNES), "episode").or(text(item, Some(PODCAST), "episode")),
+            season: text(item, Some(ITUNES), "season")
+                .or(text(item, Some(PODCAST), "season"))
+                .and_then(|season| season.parse().ok()),
+            url: link(item, namespace, "alternate"),
+            guid: text(item, namespace, "guid").or(text(item, namespace, "id")),
+            enclosure,
+        }
+    }
+}
+
+fn is(node: &Node, namespace: Option<&str>, name: &str) -> bool {
+    node.is_element() && node.tag_name().name() == name && node.tag_name().namespace() == namespace
+}
+
+fn child<'a, 'input>(
+    node: Node<'a, 'input>,
+    namespace: Option<&str>,
+    name: &str,
+) -> Option<Node<'a, 'input>> {
+    node.children().find(|child| is(child, namespace, name))
+}
+
+fn text(node: Node, namespace: Option<&str>, name: &str) -> Option<String> {
+    let text = child(node, namespace, name)?.text()?.trim();
+
+    if text.is_empty() {
+        None
+    } else {
+        Some(text.to_owned())
+    }
+}
+
+/// The url of the `<link>` of RSS, or of the `<link rel="...">` of Atom with the given relation.
+fn link(node: Node, namespace: Option<&str>, rel: &str) -> Option<Url> {
+    if namespace.is_none() && rel == "alternate" {
+        return text(node, namespace, "link")?.parse().ok();
+    }
+
+    node.children()
+        .filter(|child| is(child, Some(ATOM), "link"))
+        .find(|link| link.attribute("rel").unwrap_or("alternate") == rel)
+        .and_then(|link| link.attribute("href"))
+        .and_then(|href| href.parse().ok())
+}
+
+fn hosts(node: Node, namespace: Option<&str>) -> Vec<Name> {
+    // the role of a person defaults to host
+    let persons = node
+        .children()
+        .filter(|child| is(child, Some(PODCAST), "person"))
+        .filter(|person| {
+            person
+                .attribute("role")
+                .is_none_or(|role| role.eq_ignore_ascii_case("host"))
+        })
+        .filter_map(|person| person.text())
+        .map(name)
+        .collect::<Vec<_>>();
+    if !persons.is_empty() {
+        return persons;
+    }
+
+    // e.g. "Jane Doe & John Doe"
+    if let Some(authors) = text(node, Some(ITUNES), "author") {
+        return authors
+            .split([',', '&', ';'])
+            .flat_map(|authors| authors.split(" and "))
+            .map(str::trim)
+            .filter(|author| !author.is_empty())
+            .map(name)
+            .collect();
+    }
+
+    let atom_authors = node
+        .children()
+        .filter(|child| is(child, Some(ATOM), "author"))
+        .filter_map(|author| text(author, Some(ATOM), "name"))
+        .map(|author| name(&author))
+        .collect::<Vec<_>>();
+    if !atom_authors.is_empty() || namespace.is_some() {
+        return atom_authors;
+    }
+
+    // e.g. "jane@example.com (Jane Doe)" of RSS
+    text(node, None, "author")
+        .map(|author| match author.split_once('(') {
+            Some((_, name)) => name.trim_end_matches(')').to_owned(),
+            None => author,
+        })
+        .or(text(node, Some(DUBLIN_CORE), "creator"))
+        .map(|author| vec![name(&author)])
+        .unwrap_or_default()
+}
+
+/// A person like "Jane Doe" or a show like "The Daily" that is taken literally.
+fn name(name: &str) -> Name {
+    name.trim()
+        .parse()
+        .unwrap_or_else(|_| Name::literal(name.trim()))
+}
+
+// urls are compared without their scheme and tracking parameters, e.g. of "?utm_source=rss"
+fn comparable(url: &Url) -> String {
+    let host = url
+        .host_str()
+        .unwrap_or_default()
+        .trim_start_matches("www.");
+    let path = url.path().trim_end_matches('/');
+    let query = url
+        .query_pairs()
+        .filter(|(key, _)| !key.starts_with("utm_"))
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    format!("{host}{path}?{query}")
+}
+
+#[cfg(test)]
+mod test {
+    use super::Feed;
+
+    const EXAMPLE_FEED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+    <channel>
+        <title>Example Show</title>
+        <link>https://example.com/show</link>
+        <language>en</language>
+        <itunes:author>Jane Doe &amp; John Smith</itunes:author>
+        <itunes:owner>
+            <itunes:name>Example Radio</itunes:name>
+        </itunes:owner>
+        <item>
+            <title>The First Episode</title>
+            <link>https://example.com/show/1?utm_source=rss</link>
+            <pubDate>Sat, 15 Jun 2019 10:00:00 GMT</pubDate>
+            <itunes:duration>1:04:13</itunes:duration>
+            <itunes:episode>1</itunes:episode>
+            <enclosure url="https://cdn.example.com/1.mp3" type="audio/mpeg" length="1"/>
+        </item>
+    </channel>
+</rss>"#;
+
+    #[test]
+    fn feed_episode() {
+        let feed = Feed::parse(EXAMPLE_FEED).unwrap();
+
+        assert_eq!(feed.show().title.as_deref(), Some("Example Show"));
+        assert_eq!(feed.show().owner.as_deref(), Some("Example Radio"));
+        assert_eq!(feed.show().hosts.len(), 2);
+
+        let url = "http://www.example.com/show/1/".parse().unwrap();
+        let episode = feed.episode(&url).unwrap();
+
+        assert_eq!(episode.title.as_deref(), Some("The First Episode"));
+        assert_eq!(episode.number.as_deref(), Some("1"));
+        assert_eq!(episode.runtime.map(|runtime| runtime.seconds), Some(3853));
+        assert_eq!(episode.published.map(|date| date.year), Some(2019));
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{
+    dom::Dom,
+    error::{MetaError, MetaResult},
+    meta::value::Name,
+};
+use regex::Regex;
+use scraper::{ElementRef, Selector};
+use std::sync::OnceLock;
+use url::Url;
+
+// e.g. "©", "Photo:" or "Illustration by"
+const CREDIT_PREFIX: &str =
+    r"(?i:©|\(c\)|(?:photo(?:graph)?|image|illustration|credit|foto)\s*(?:by\b|:))";
+
+static CAPTION_CREDIT_REGEX: OnceLock<Regex> = OnceLock::new();
+static CREDIT_PREFIX_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// An image or a figure of a page, which is cited on its own within the citation of the page.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Figure {
+    /// The caption without the credit.
+    pub caption: Option<String>,
+    /// The photographer, illustrator or agency the image is credited to.
+    pub credit: Option<Name>,
+    /// The alternative text, which describes the image if it has no caption.
+    pub alt: Option<String>,
+    pub image: Option<Url>,
+}
+
+impl Figure {
+    /// The first `<img>` or `<figure>` matching the selector, e.g. "figure.lead" or "#hero img".
+    pub fn select(dom: &Dom, selector: &str) -> MetaResult<Self> {
+        // the error of a selector given at runtime borrows the selector
+        let selector =
+            Selector::parse(selector).map_err(|err| MetaError::InvalidSelector(err.to_string()))?;
+        let el = dom
+            .html()
+            .select(&selector)
+            .next()
+            .ok_or(MetaError::ElementNotFound)?;
+
+        let img_selector = Selector::parse("img")?;
+        let caption_selector = Selector::parse("figcaption")?;
+        let credit_selector = Selector::parse(
+            ".credit, [class*=\"credit\"], [class*=\"byline\"], \
+             [itemprop=\"copyrightHolder\"], [itemprop=\"creator\"], figcaption cite",
+        )?;
+
+        let figure = if el.value().name() == "figure" {
+            Some(el)
+        } else {
+            el.ancestors()
+                .filter_map(ElementRef::wrap)
+                .find(|ancestor| ancestor.value().name() == "figure")
+        };
+        let img = if el.value().name() == "img" {
+            Some(el)
+        } else {
+            el.select(&img_selector).next()
+        };
+
+        let text = |el: ElementRef| {
+            let text = el.text().collect::<Vec<_>>().join(" ");
+            Some(text.split_whitespace().collect::<Vec<_>>().join(" "))
+                .filter(|text| !text.is_empty())
+        };
+
+        let caption = figure
+            .and_then(|figure| figure.select(&caption_selector).next())
+            .and_then(text);
+        let credit = match figure {
+            Some(figure) => figure.select(&credit_selector).filter_map(text).next(),
+            // an image without a figure is followed by its credit, e.g.
+            // `<img><span class="credit">` or `<a><img></a><small class="credit">`
+            None => img.and_then(|img| {
+                let parent = img
+                    .parent()
+                    .and_then(ElementRef::wrap)
+                    .filter(|parent| matches!(parent.value().name(), "a" | "picture"));
+                [Some(img), parent]
+                    .into_iter()
+                    .flatten()
+                    .flat_map(|el| el.next_siblings().filter_map(ElementRef::wrap))
+                    .find_map(|sibling| {
+                        if credit_selector.matches(&sibling) {
+                            text(sibling)
+                        } else {
+                            sibling.select(&credit_selector).find_map(text)
+                        }
+                    })
+            }),
+        };
+
+        // the credit is either marked up within the caption or written at its end
+        let (caption, credit) = match (caption, credit) {
+            (caption, Some(credit)) => (
+                caption.map(|caption| caption.replace(&credit, "")),
+                Some(clean_credit(&credit).to_owned()),
+            ),
+            (Some(caption), None) => split_credit(&caption),
+            (None, None) => (None, None),
+        };
+        let caption = caption
+            .map(|caption| {
+                caption
+                    .trim()
+                    .trim_end_matches(['|', '/', '-', '—'])
+                    .trim()
+                    .to_owned()
+            })
+            .filter(|caption| !caption.is_empty());
+
+        let image = img
+            .and_then(|img| {
+                let img = img.value();
+                img.attr("src")
+                    .filter(|src| !src.starts_with("data:"))
+                    .or(img.attr("data-src"))
+                    .or(img
+                        .attr("srcset")
+                        .and_then(|srcset| srcset.split_whitespace().next()))
+            })
+            .and_then(|src| dom.url().join(src).ok());
+
+        Ok(Self {
+            caption,
+            credit: credit.map(|credit| {
+                credit
+                    .parse()
+                    .unwrap_or_else(|_| Name::literal(credit.trim()))
+            }),
+            alt: img
+                .and_then(|img| img.value().attr("alt"))
+                .map(str::trim)
+                .filter(|alt| !alt.is_empty())
+                .map(ToOwned::to_owned),
+            image,
+        })
+    }
+}
+
+/// Splits a credit like "Photo: Jane Doe" or "© Jane Doe" from the end of a caption.
+fn split_credit(caption: &str) -> (Option<String>, Option<String>) {
+    let regex = CAPTION_CREDIT_REGEX.get_or_init(|| {
+        Regex::new(&format!(r"[\s.(]*{CREDIT_PREFIX}\s*([^()]+?)\)?\s*$")).unwrap()
+    });
+
+    match regex.captures(caption) {
+        Some(captures) => {
+            let start = captures.get(0).map_or(caption.len(), |all| all.start());
+            let credit = captures.get(1).map(|credit| credit.as_str().to_owned());
+            (Some(caption[..start].to_owned()), credit)
+        }
+        None => (Some(caption.to_owned()), None),
+    }
+}
+
+// e.g. "Jane Doe" of a credit element like "Photo: Jane Doe"
+fn clean_credit(credit: &str) -> &str {
+    let regex = CREDIT_PREFIX_REGEX
+        .get_or_init(|| Regex::new(&format!(r"^\s*{CREDIT_PREFIX}\s*")).unwrap());
+
+    match regex.find(credit) {
+        Some(prefix) => &credit[prefix.end()..],
+        None => credit,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{split_credit, Figure};
+    use crate::dom::Dom;
+
+    #[test]
+    fn caption_credit() {
+        let (caption, credit) = split_credit("The harbour at dawn. Photo: Jane Doe/Reuters");
+        assert_eq!(caption.as_deref(), Some("The harbour at dawn"));
+        assert_eq!(credit.as_deref(), Some("Jane Doe/Reuters"));
+
+        let (caption, credit) = split_credit("The harbour at dawn (© Jane Doe)");
+        assert_eq!(caption.as_deref(), Some("The harbour at dawn"));
+        assert_eq!(credit.as_deref(), Some("Jane Doe"));
+
+        assert_eq!(split_credit("A photograph of the harbour").1, None);
+    }
+
+    #[test]
+    fn credit_next_to_image() {
+        let html = r#"<html><body><article>
+            <p class="byline">By John Roe</p>
+            <div class="lead">
+                <a href="/harbour.jpg"><img src="/harbour.jpg" alt="The harbour"></a>
+                <small class="image-credit">Photo: Jane Doe</small>
+            </div>
+        </article></body></html>"#;
+        let dom = Dom::parse(html, "https://example.org/news/harbour").unwrap();
+        let figure = Figure::select(&dom, ".lead img").unwrap();
+
+        assert_eq!(
+            figure.credit.map(|credit| credit.surname),
+            Some("Doe".to_owned())
+        );
+        assert_eq!(figure.alt.as_deref(), Some("The harbour"));
+    }
+}
@@ -3,15 +3,20 @@
 
 pub use citation::*;
 pub use dom::Dom;
+pub use feed::Feed;
 use log::Level;
 use wasm_bindgen::prelude::wasm_bindgen;
 
 pub mod citation;
 pub mod dom;
+pub mod entry;
 pub mod error;
+pub mod feed;
+pub mod figure;
 pub mod meta;
 pub mod priority;
 pub mod query;
+pub mod site;
 
 #[wasm_bindgen(start)]
 pub fn init_console() {
@@ -0,0 +1,122 @@
+use super::value::{Doi, Name, PartialDate};
+use crate::error::{MetaError, MetaResult};
+use url::Url;
+use yaml_rust::{Yaml, YamlLoader};
+
+/// The content of a `CITATION.cff` file, with which software authors describe how to cite their work.
+/// See https://citation-file-format.github.io
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CitationFile {
+    pub title: Option<String>,
+    /// The persons or entities, e.g. a company, that authored the software.
+    pub authors: Vec<Name>,
+    pub version: Option<String>,
+    pub date_released: Option<PartialDate>,
+    pub doi: Option<Doi>,
+    /// The SPDX identifier of the license, e.g. "MIT".
+    pub license: Option<String>,
+    /// The url of the source code repository.
+    pub repository: Option<Url>,
+}
+
+impl CitationFile {
+    pub fn parse(content: &str) -> MetaResult<Self> {
+        let documents = YamlLoader::load_from_str(content).map_err(|_| MetaError::YamlParse)?;
+        let cff = documents.into_iter().next().ok_or(MetaError::YamlParse)?;
+
+        if cff["cff-version"].is_badvalue() {
+            return Err(MetaError::WrongMetadataType);
+        }
+
+        let authors = cff["authors"]
+            .as_vec()
+            .map(|authors| authors.iter().filter_map(as_name).collect())
+            .unwrap_or_default();
+
+        // a license may also be a list of SPDX identifiers
+        let license = match &cff["license"] {
+            Yaml::Array(licenses) => {
+                let licenses = licenses.iter().filter_map(as_text).collect::<Vec<_>>();
+                Some(licenses.join(" OR ")).filter(|license| !license.is_empty())
+            }
+            license => as_text(license),
+        };
+
+        Ok(Self {
+            title: as_text(&cff["title"]),
+            authors,
+            version: as_text(&cff["version"]),
+            date_released: as_text(&cff["date-released"]).and_then(|date| date.parse().ok()),
+            doi: as_text(&cff["doi"]).and_then(|doi| doi.parse().ok()),
+            license,
+            repository: as_text(&cff["repository-code"]).and_then(|url| url.parse().ok()),
+        })
+    }
+}
+
+fn as_text(value: &Yaml) -> Option<String> {
+    let text = match value {
+        Yaml::String(text) | Yaml::Real(text) => text.trim().to_owned(),
+        Yaml::Integer(number) => number.to_string(),
+        _ => return None,
+    };
+
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+fn as_name(author: &Yaml) -> Option<Name> {
+    if let Some(surname) = as_text(&author["family-names"]) {
+        return Some(Name {
+            surname,
+            given_name: as_text(&author["given-names"]),
+            prefix: as_text(&author["name-particle"]),
+            suffix: as_text(&author["name-suffix"]),
+            alias: as_text(&author["alias"]),
+        });
+    }
+
+    // an entity like a company or a project team
+    let name = as_text(&author["name"]).or(as_text(&author["alias"]))?;
+    Some(Name::literal(name))
+}
+
+#[cfg(test)]
+mod test {
+    use super::CitationFile;
+    use crate::meta::value::Name;
+
+    #[test]
+    fn citation_file_parse() {
+        let cff = CitationFile::parse(
+            "cff-version: 1.2.0
+message: If you use this software, please cite it as below.
+title: My Research Software
+version: 2.0.4
+date-released: 2021-08-11
+license: MIT
+authors:
+  - family-names: Beethoven
+    name-particle: van
+    given-names: Ludwig
+  - name: The Research Software project
+",
+        )
+        .unwrap();
+
+        assert_eq!(cff.title.as_deref(), Some("My Research Software"));
+        assert_eq!(cff.version.as_deref(), Some("2.0.4"));
+        assert_eq!(cff.license.as_deref(), Some("MIT"));
+        assert_eq!(cff.date_released.map(|date| date.day), Some(Some(11)));
+        assert_eq!(cff.authors.len(), 2);
+        assert_eq!(cff.authors[0].surname, "Beethoven");
+        assert_eq!(cff.authors[0].prefix.as_deref(), Some("van"));
+        assert_eq!(
+            cff.authors[1],
+            Name::literal("The Research Software project")
+        );
+    }
+}
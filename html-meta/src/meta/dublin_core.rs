@@ -0,0 +1,191 @@
+use super::{
+    extractor::MetadataExtractor,
+    item::{MetadataItem, MetadataItemExt, MetadataItemExtractor, MetadataItemTarget},
+    value::{Doi, Name, PartialDate, Title},
+};
+use crate::error::MetaResult;
+use scraper::{html::Select, ElementRef, Selector};
+
+/// The `DC.*` and `DCTERMS.*` meta tags of the Dublin Core Metadata Initiative,
+/// as emitted by institutional repositories like DSpace.
+#[derive(Debug, Default)]
+pub struct DublinCoreMetadata {
+    pub title: Option<Title>,
+    pub creators: Vec<Name>,
+    pub date: Option<PartialDate>,
+    /// The kind of the resource, e.g. "Dataset" for DataCite records.
+    pub kind: Option<String>,
+    pub publisher: Option<String>,
+    pub doi: Option<Doi>,
+    /// The license or rights statement, e.g. "CC BY 4.0".
+    pub rights: Option<String>,
+    /// The series and number within the series, e.g. "Working Paper;2019-12".
+    pub series: Option<String>,
+}
+
+impl DublinCoreMetadata {
+    pub fn extract(select: Select) -> Self {
+        let mut target = Self::default();
+
+        let items = [
+            DublinCoreMetadataItem::Title.to_box(),
+            DublinCoreMetadataItem::Creator.to_box(),
+            DublinCoreMetadataItem::Date.to_box(),
+            DublinCoreMetadataItem::Kind.to_box(),
+            DublinCoreMetadataItem::Identifier.to_box(),
+            DublinCoreMetadataItem::Rights.to_box(),
+            DublinCoreMetadataItem::Publisher.to_box(),
+            DublinCoreMetadataItem::Series.to_box(),
+        ];
+
+        let mut extractor = MetadataExtractor::new();
+
+        let errors = extractor.extract_to(select, items, &mut target);
+        errors.print_failure();
+
+        target
+    }
+
+    /// Whether the resource is a dataset according to its DataCite resource type.
+    pub fn is_dataset(&self) -> bool {
+        self.kind
+            .as_ref()
+            .is_some_and(|kind| kind.eq_ignore_ascii_case("dataset"))
+    }
+
+    /// Returns the series name and the number within the series
+    /// if the series is one of working papers, discussion papers or reports.
+    pub fn report_series(&self) -> Option<(&str, &str)> {
+        let (name, number) = self.series.as_ref()?.split_once(';')?;
+        let (name, number) = (name.trim(), number.trim());
+
+        let lowercase = name.to_lowercase();
+        let is_report = ["working paper", "discussion paper", "report"]
+            .iter()
+            .any(|kind| lowercase.contains(kind));
+
+        let number = number
+            .trim_start_matches("no.")
+            .trim_start_matches("No.")
+            .trim();
+
+        if is_report && !number.is_empty() {
+            Some((name, number))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum DublinCoreMetadataItem {
+    Title,
+    Creator,
+    Date,
+    Kind,
+    Identifier,
+    Rights,
+    Publisher,
+    Series,
+}
+
+impl MetadataItem for DublinCoreMetadataItem {
+    fn selector(&self) -> MetaResult<Selector> {
+        let selector = match self {
+            Self::Title => {
+                Selector::parse("meta[name=\"DC.title\" i], meta[name=\"DCTERMS.title\" i]")
+            }
+            Self::Creator => Selector::parse(
+                "meta[name=\"DC.creator\" i], meta[name=\"DCTERMS.creator\" i]",
+            ),
+            Self::Date => Selector::parse(
+                "meta[name=\"DC.date\" i], meta[name=\"DCTERMS.issued\" i], meta[name=\"DC.date.issued\" i]",
+            ),
+            Self::Kind => {
+                Selector::parse("meta[name=\"DC.type\" i], meta[name=\"DCTERMS.type\" i]")
+            }
+            Self::Identifier => Selector::parse(
+                "meta[name=\"DC.identifier\" i], meta[name=\"DCTERMS.identifier\" i]",
+            ),
+            Self::Rights => Selector::parse(
+                "meta[name=\"DC.rights\" i], meta[name=\"DCTERMS.license\" i], meta[name=\"DCTERMS.rights\" i]",
+            ),
+            Self::Publisher => {
+                Selector::parse("meta[name=\"DC.publisher\" i], meta[name=\"DCTERMS.publisher\" i]")
+            }
+            Self::Series => Selector::parse("meta[name=\"DC.relation.ispartofseries\" i]"),
+        }?;
+
+        Ok(selector)
+    }
+
+    fn target(&self) -> MetadataItemTarget {
+        MetadataItemTarget::Attr("content")
+    }
+
+    fn multiple(&self) -> bool {
+        matches!(self, Self::Creator | Self::Identifier)
+    }
+}
+
+impl MetadataItemExt for DublinCoreMetadataItem {}
+
+impl MetadataItemExtractor<DublinCoreMetadata> for DublinCoreMetadataItem {
+    fn extract_item_to(
+        &mut self,
+        el: &ElementRef,
+        target: &mut DublinCoreMetadata,
+    ) -> MetaResult<()> {
+        match self {
+            Self::Title => target.title = Some(self.extract::<Title>(el)?),
+            Self::Creator => target.creators.push(self.extract::<Name>(el)?),
+            Self::Date => target.date = Some(self.extract::<PartialDate>(el)?),
+            Self::Kind => target.kind = Some(self.extract::<String>(el)?),
+            // repositories list all kinds of identifiers, of which only the doi is of interest
+            Self::Identifier => {
+                if let Ok(doi) = self.extract::<Doi>(el) {
+                    target.doi = Some(doi);
+                }
+            }
+            Self::Rights => target.rights = Some(self.extract::<String>(el)?),
+            Self::Publisher => target.publisher = Some(self.extract::<String>(el)?),
+            Self::Series => target.series = Some(self.extract::<String>(el)?),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DublinCoreMetadata;
+
+    fn report_series(series: &str) -> Option<(String, String)> {
+        let metadata = DublinCoreMetadata {
+            series: Some(series.to_owned()),
+            ..Default::default()
+        };
+        metadata
+            .report_series()
+            .map(|(name, number)| (name.to_owned(), number.to_owned()))
+    }
+
+    #[test]
+    fn report_series_number() {
+        assert_eq!(
+            report_series("Kiel Working Paper; No. 2140"),
+            Some(("Kiel Working Paper".to_owned(), "2140".to_owned()))
+        );
+        assert_eq!(
+            report_series("IZA Discussion Papers;12345"),
+            Some(("IZA Discussion Papers".to_owned(), "12345".to_owned()))
+        );
+        // series of other works and series without a number are no reports
+        assert_eq!(
+            report_series("Lecture Notes in Computer Science;11234"),
+            None
+        );
+        assert_eq!(report_series("Working Paper;"), None);
+        assert_eq!(report_series("Working Paper 12"), None);
+    }
+}
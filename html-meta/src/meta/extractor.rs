@@ -23,7 +23,7 @@ impl<T> MetadataExtractor<T> {
 
             if let Some(matches) = errors.capture(item.matches(el)) && matches {
                 errors.capture(item.extract_item_to(el, target));
-                *skip = true;
+                *skip = !item.multiple();
             }
         }
 
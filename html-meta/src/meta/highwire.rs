@@ -0,0 +1,170 @@
+use super::{
+    extractor::MetadataExtractor,
+    item::{MetadataItem, MetadataItemExt, MetadataItemExtractor, MetadataItemTarget},
+    value::{Name, PartialDate, Title},
+};
+use crate::error::MetaResult;
+use isbn2::Isbn;
+use scraper::{html::Select, ElementRef, Selector};
+use unic_langid::LanguageIdentifier;
+
+/// The `citation_*` meta tags introduced by Highwire Press and used by Google Scholar.
+#[derive(Debug, Default)]
+pub struct HighwireMetadata {
+    /// The title of the paper.
+    pub title: Option<Title>,
+    /// The authors of the paper, one tag per author.
+    pub authors: Vec<Name>,
+    /// The editors of the publication the paper is contained in.
+    pub editors: Vec<Name>,
+    /// When the paper was published, e.g. "2019/06/15" or "2019".
+    pub publication_date: Option<PartialDate>,
+    /// An alternative to the publication date used by some publishers.
+    pub date: Option<PartialDate>,
+    /// The title of the proceedings the paper was published in.
+    pub conference_title: Option<String>,
+    /// The name of the conference the paper was presented at.
+    pub conference: Option<String>,
+    /// The institution that issued the technical report.
+    pub technical_report_institution: Option<String>,
+    /// The number of the technical report, e.g. "TR-2019-12".
+    pub technical_report_number: Option<String>,
+    pub publisher: Option<String>,
+    pub firstpage: Option<String>,
+    pub lastpage: Option<String>,
+    pub doi: Option<String>,
+    pub isbn: Option<Isbn>,
+    pub language: Option<LanguageIdentifier>,
+}
+
+impl HighwireMetadata {
+    pub fn extract(select: Select) -> Self {
+        let mut target = Self::default();
+
+        let items = [
+            HighwireMetadataItem::Title.to_box(),
+            HighwireMetadataItem::Author.to_box(),
+            HighwireMetadataItem::Editor.to_box(),
+            HighwireMetadataItem::PublicationDate.to_box(),
+            HighwireMetadataItem::Date.to_box(),
+            HighwireMetadataItem::ConferenceTitle.to_box(),
+            HighwireMetadataItem::Conference.to_box(),
+            HighwireMetadataItem::TechnicalReportInstitution.to_box(),
+            HighwireMetadataItem::TechnicalReportNumber.to_box(),
+            HighwireMetadataItem::Publisher.to_box(),
+            HighwireMetadataItem::FirstPage.to_box(),
+            HighwireMetadataItem::LastPage.to_box(),
+            HighwireMetadataItem::Doi.to_box(),
+            HighwireMetadataItem::Isbn.to_box(),
+            HighwireMetadataItem::Language.to_box(),
+        ];
+
+        let mut extractor = MetadataExtractor::new();
+
+        let errors = extractor.extract_to(select, items, &mut target);
+        errors.print_failure();
+
+        target
+    }
+
+    /// Whether the paper was published in the proceedings of a conference.
+    pub fn is_conference_paper(&self) -> bool {
+        self.conference_title.is_some() || self.conference.is_some()
+    }
+
+    /// Whether the paper was issued as a technical report of an institution.
+    pub fn is_technical_report(&self) -> bool {
+        self.technical_report_institution.is_some() || self.technical_report_number.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum HighwireMetadataItem {
+    Title,
+    Author,
+    Editor,
+    PublicationDate,
+    Date,
+    ConferenceTitle,
+    Conference,
+    TechnicalReportInstitution,
+    TechnicalReportNumber,
+    Publisher,
+    FirstPage,
+    LastPage,
+    Doi,
+    Isbn,
+    Language,
+}
+
+impl MetadataItem for HighwireMetadataItem {
+    fn selector(&self) -> MetaResult<Selector> {
+        let selector = match self {
+            Self::Title => Selector::parse("meta[name=\"citation_title\"]"),
+            Self::Author => Selector::parse("meta[name=\"citation_author\"]"),
+            Self::Editor => Selector::parse("meta[name=\"citation_editor\"]"),
+            Self::PublicationDate => Selector::parse("meta[name=\"citation_publication_date\"]"),
+            Self::Date => Selector::parse("meta[name=\"citation_date\"]"),
+            Self::ConferenceTitle => Selector::parse("meta[name=\"citation_conference_title\"]"),
+            Self::Conference => Selector::parse("meta[name=\"citation_conference\"]"),
+            Self::TechnicalReportInstitution => {
+                Selector::parse("meta[name=\"citation_technical_report_institution\"]")
+            }
+            Self::TechnicalReportNumber => {
+                Selector::parse("meta[name=\"citation_technical_report_number\"]")
+            }
+            Self::Publisher => Selector::parse("meta[name=\"citation_publisher\"]"),
+            Self::FirstPage => Selector::parse("meta[name=\"citation_firstpage\"]"),
+            Self::LastPage => Selector::parse("meta[name=\"citation_lastpage\"]"),
+            Self::Doi => Selector::parse("meta[name=\"citation_doi\"]"),
+            Self::Isbn => Selector::parse("meta[name=\"citation_isbn\"]"),
+            Self::Language => Selector::parse("meta[name=\"citation_language\"]"),
+        }?;
+
+        Ok(selector)
+    }
+
+    fn target(&self) -> MetadataItemTarget {
+        MetadataItemTarget::Attr("content")
+    }
+
+    fn multiple(&self) -> bool {
+        matches!(self, Self::Author | Self::Editor)
+    }
+}
+
+impl MetadataItemExt for HighwireMetadataItem {}
+
+impl MetadataItemExtractor<HighwireMetadata> for HighwireMetadataItem {
+    fn extract_item_to(
+        &mut self,
+        el: &ElementRef,
+        target: &mut HighwireMetadata,
+    ) -> MetaResult<()> {
+        match self {
+            Self::Title => target.title = Some(self.extract::<Title>(el)?),
+            Self::Author => target.authors.push(self.extract::<Name>(el)?),
+            Self::Editor => target.editors.push(self.extract::<Name>(el)?),
+            Self::PublicationDate => {
+                target.publication_date = Some(self.extract::<PartialDate>(el)?)
+            }
+            Self::Date => target.date = Some(self.extract::<PartialDate>(el)?),
+            Self::ConferenceTitle => target.conference_title = Some(self.extract::<String>(el)?),
+            Self::Conference => target.conference = Some(self.extract::<String>(el)?),
+            Self::TechnicalReportInstitution => {
+                target.technical_report_institution = Some(self.extract::<String>(el)?)
+            }
+            Self::TechnicalReportNumber => {
+                target.technical_report_number = Some(self.extract::<String>(el)?)
+            }
+            Self::Publisher => target.publisher = Some(self.extract::<String>(el)?),
+            Self::FirstPage => target.firstpage = Some(self.extract::<String>(el)?),
+            Self::LastPage => target.lastpage = Some(self.extract::<String>(el)?),
+            Self::Doi => target.doi = Some(self.extract::<String>(el)?),
+            Self::Isbn => target.isbn = Some(self.extract::<Isbn>(el)?),
+            Self::Language => target.language = Some(self.extract::<LanguageIdentifier>(el)?),
+        }
+
+        Ok(())
+    }
+}
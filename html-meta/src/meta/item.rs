@@ -32,6 +32,10 @@ impl MetadataItemTarget {
 pub trait MetadataItem {
     fn selector(&self) -> MetaResult<Selector>;
     fn target(&self) -> MetadataItemTarget;
+    /// Whether the item may occur multiple times, e.g. one meta tag per author.
+    fn multiple(&self) -> bool {
+        false
+    }
     fn matches(&self, el: &ElementRef) -> MetaResult<bool> {
         let selector = self.selector()?;
         Ok(selector.matches(el))
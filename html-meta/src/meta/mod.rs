@@ -1,5 +1,9 @@
+pub mod cff;
+pub mod dublin_core;
 pub mod extractor;
 pub mod generic;
+pub mod highwire;
 pub mod item;
 pub mod ogp;
+pub mod schema;
 pub mod value;
@@ -14,6 +14,7 @@ pub enum OgpArticleItem {
     ModifiedTime,
     ExpirationTime,
     Author,
+    Publisher,
     Section,
     Tag,
 }
@@ -22,14 +23,27 @@ impl MetadataItem for OgpArticleItem {
     fn selector(&self) -> MetaResult<Selector> {
         let selector = match self {
             Self::Check => Selector::parse("meta[property=\"og:type\"][content=\"article\"]")?,
-            Self::PublishedTime => Selector::parse("meta[property=\"og:article:published_time\"]")?,
-            Self::ModifiedTime => Selector::parse("meta[property=\"og:article:modified_time\"]")?,
-            Self::ExpirationTime => {
-                Selector::parse("meta[property=\"og:article:expiration_time\"]")?
-            }
-            Self::Author => Selector::parse("meta[property=\"og:article:author\"]")?,
-            Self::Section => Selector::parse("meta[property=\"og:article:section\"]")?,
-            Self::Tag => Selector::parse("meta[property=\"og:article:tag\"]")?,
+            Self::PublishedTime => Selector::parse(
+                "meta[property=\"article:published_time\"], meta[property=\"og:article:published_time\"]",
+            )?,
+            Self::ModifiedTime => Selector::parse(
+                "meta[property=\"article:modified_time\"], meta[property=\"og:article:modified_time\"]",
+            )?,
+            Self::ExpirationTime => Selector::parse(
+                "meta[property=\"article:expiration_time\"], meta[property=\"og:article:expiration_time\"]",
+            )?,
+            Self::Author => Selector::parse(
+                "meta[property=\"article:author\"], meta[property=\"og:article:author\"]",
+            )?,
+            Self::Publisher => Selector::parse(
+                "meta[property=\"article:publisher\"], meta[property=\"og:article:publisher\"]",
+            )?,
+            Self::Section => Selector::parse(
+                "meta[property=\"article:section\"], meta[property=\"og:article:section\"]",
+            )?,
+            Self::Tag => Selector::parse(
+                "meta[property=\"article:tag\"], meta[property=\"og:article:tag\"]",
+            )?,
         };
 
         Ok(selector)
@@ -69,6 +83,10 @@ impl MetadataItemExtractor<OgpMetadata> for OgpArticleItem {
                 let tag = self.extract(el)?;
                 target.article.tags.push(tag);
             }
+            Self::Publisher => {
+                let publisher = self.extract(el)?;
+                target.article.publisher = Some(publisher);
+            }
             Self::Section => {
                 let section = self.extract(el)?;
                 target.article.section = Some(section);
@@ -90,6 +108,8 @@ pub struct OgpArticle {
     pub expiration_time: Option<DateIso8601>,
     /// Writers of the article.
     pub authors: Vec<Name>,
+    /// The publisher of the article, usually the url of its Facebook page.
+    pub publisher: Option<String>,
     /// A high-level section name. E.g. Technology
     pub section: Option<String>,
     /// Tag words associated with this article.
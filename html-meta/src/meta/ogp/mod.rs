@@ -48,6 +48,13 @@ pub struct OgpMetadata {
 }
 
 impl OgpMetadata {
+    /// Whether the page is an article of a publisher, as newspapers link their Facebook page.
+    /// The name of the site tells little, e.g. of "Journal of Physics" or "The Register",
+    /// and blogs built with Hugo or Rank Math file their posts under a section too.
+    pub fn is_newspaper(&self) -> bool {
+        self.article.is_article && self.article.publisher.is_some()
+    }
+
     pub fn extract(select: Select) -> Self {
         let mut target = Self::default();
 
@@ -64,6 +71,7 @@ impl OgpMetadata {
             OgpArticleItem::ExpirationTime.to_box(),
             OgpArticleItem::ModifiedTime.to_box(),
             OgpArticleItem::PublishedTime.to_box(),
+            OgpArticleItem::Publisher.to_box(),
             OgpArticleItem::Section.to_box(),
             OgpArticleItem::Tag.to_box(),
             OgpAudioItem::Mime.to_box(),
@@ -0,0 +1,185 @@
+use super::value::{Name, PartialDate};
+use crate::error::{Errors, MetaError, MetaResult};
+use scraper::html::Select;
+use serde_json::{Map, Value};
+use url::Url;
+
+/// The schema.org objects embedded as JSON-LD in `<script type="application/ld+json">`.
+#[derive(Debug, Default)]
+pub struct SchemaMetadata {
+    pub objects: Vec<SchemaObject>,
+}
+
+impl SchemaMetadata {
+    pub fn extract(select: Select) -> Self {
+        let mut target = Self::default();
+        let mut errors = Errors::new();
+
+        for el in select {
+            let json = el.text().collect::<String>();
+            let value = serde_json::from_str::<Value>(&json).map_err(MetaError::from);
+
+            if let Some(value) = errors.capture(value) {
+                target.push(value);
+            }
+        }
+        errors.print_failure();
+
+        target
+    }
+
+    fn push(&mut self, value: Value) {
+        match value {
+            Value::Array(values) => {
+                for value in values {
+                    self.push(value);
+                }
+            }
+            Value::Object(mut object) => {
+                if let Some(graph) = object.remove("@graph") {
+                    self.push(graph);
+                }
+                if object.contains_key("@type") {
+                    self.objects.push(SchemaObject(object));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Returns the first object which has one of the given types.
+    pub fn find(&self, kinds: &[&str]) -> Option<&SchemaObject> {
+        self.objects.iter().find(|object| object.is_any(kinds))
+    }
+}
+
+/// A single JSON-LD object like `{"@type": "ScholarlyArticle", "name": "..."}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaObject(Map<String, Value>);
+
+impl SchemaObject {
+    /// Parses a standalone JSON-LD object, e.g. the content of a `codemeta.json` file.
+    pub fn parse(json: &str) -> MetaResult<Self> {
+        match serde_json::from_str::<Value>(json)? {
+            Value::Object(object) if object.contains_key("@type") => Ok(Self(object)),
+            _ => Err(MetaError::WrongMetadataType),
+        }
+    }
+
+    /// The schema.org types of the object without any vocabulary prefix.
+    pub fn types(&self) -> Vec<&str> {
+        values(self.0.get("@type"))
+            .filter_map(Value::as_str)
+            .map(|kind| kind.rsplit(['/', ':']).next().unwrap_or(kind))
+            .collect()
+    }
+
+    pub fn is_any(&self, kinds: &[&str]) -> bool {
+        self.types().iter().any(|kind| kinds.contains(kind))
+    }
+
+    /// The first text of the property. Nested objects are represented by their name.
+    pub fn text(&self, key: &str) -> Option<String> {
+        self.texts(key).into_iter().next()
+    }
+
+    pub fn texts(&self, key: &str) -> Vec<String> {
+        values(self.0.get(key)).filter_map(as_text).collect()
+    }
+
+    pub fn object(&self, key: &str) -> Option<SchemaObject> {
+        self.objects(key).into_iter().next()
+    }
+
+    pub fn objects(&self, key: &str) -> Vec<SchemaObject> {
+        values(self.0.get(key))
+            .filter_map(Value::as_object)
+            .map(|object| SchemaObject(object.clone()))
+            .collect()
+    }
+
+    /// The persons or organizations of the property, e.g. of "author".
+    pub fn names(&self, key: &str) -> Vec<Name> {
+        values(self.0.get(key)).filter_map(as_name).collect()
+    }
+
+    pub fn date(&self, key: &str) -> Option<PartialDate> {
+        self.text(key)?.parse().ok()
+    }
+
+    pub fn url(&self, key: &str) -> Option<Url> {
+        self.text(key)?.parse().ok()
+    }
+
+    /// The human readable location of a `Place` or `PostalAddress`, e.g. "Amsterdam, Netherlands".
+    pub fn place(&self, key: &str) -> Option<String> {
+        let place = match self.object(key) {
+            Some(place) => place,
+            None => return self.text(key),
+        };
+
+        let address = place.object("address").unwrap_or(place.clone());
+        let parts = ["addressLocality", "addressRegion", "addressCountry"]
+            .into_iter()
+            .filter_map(|key| address.text(key))
+            .collect::<Vec<_>>();
+
+        if !parts.is_empty() {
+            Some(parts.join(", "))
+        } else {
+            place.text("name").or(address.text("streetAddress"))
+        }
+    }
+}
+
+fn values(value: Option<&Value>) -> impl Iterator<Item = &Value> {
+    let values = match value {
+        Some(Value::Array(values)) => values.iter().collect(),
+        Some(value) => vec![value],
+        None => vec![],
+    };
+    values.into_iter()
+}
+
+fn as_text(value: &Value) -> Option<String> {
+    let text = match value {
+        Value::String(text) => text.trim().to_owned(),
+        Value::Number(number) => number.to_string(),
+        Value::Object(object) => object
+            .get("name")
+            .or(object.get("@value"))
+            .or(object.get("value"))
+            .and_then(as_text)?,
+        _ => return None,
+    };
+
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+fn as_name(value: &Value) -> Option<Name> {
+    let object = match value {
+        Value::Object(object) => SchemaObject(object.clone()),
+        value => return as_text(value)?.parse().ok(),
+    };
+
+    if let Some(surname) = object.text("familyName") {
+        return Some(Name {
+            surname,
+            given_name: object.text("givenName"),
+            prefix: object.text("honorificPrefix"),
+            suffix: object.text("honorificSuffix"),
+            alias: None,
+        });
+    }
+
+    let name = object.text("name")?;
+    if object.is_any(&["Person"]) {
+        name.parse().ok()
+    } else {
+        Some(Name::literal(name))
+    }
+}
@@ -3,6 +3,23 @@ use std::str::FromStr;
 use chrono::{DateTime, Datelike, FixedOffset, Local, ParseError};
 use hayagriva::types::Date;
 
+use crate::error::MetaError;
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct DateIso8601 {
     date_time: DateTime<FixedOffset>,
@@ -35,3 +52,195 @@ impl From<DateIso8601> for Date {
         }
     }
 }
+
+/// A calendar date of which only the year is guaranteed to be known,
+/// e.g. "2019", "2019-06" or "2019/06/15" as used by scholarly meta tags.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PartialDate {
+    pub year: i32,
+    /// The month starting at 1.
+    pub month: Option<u8>,
+    /// The day starting at 1.
+    pub day: Option<u8>,
+}
+
+impl PartialDate {
+    /// Drops the month and the day, e.g. for the publication year of proceedings.
+    pub fn year_only(self) -> Self {
+        Self {
+            year: self.year,
+            month: None,
+            day: None,
+        }
+    }
+
+    /// A date with the name of its month, e.g. "Tue Jan  5 10:00:00 CET 2021"
+    /// or "Tuesday, 5 January 2021", as shown on pages instead of in their metadata.
+    pub fn from_words(text: &str) -> Option<Self> {
+        let mut year = None;
+        let mut month = None;
+        let mut day = None;
+
+        for token in text.split([' ', ',']).filter(|token| !token.is_empty()) {
+            if let Some(position) = month_position(token) {
+                month = Some(position as u8 + 1);
+            } else if token.len() == 4
+                && let Ok(number) = token.parse()
+            {
+                year = Some(number);
+            } else if token.len() <= 2
+                && let Ok(number) = token.parse::<u8>()
+                && (1..=31).contains(&number)
+            {
+                day = Some(number);
+            }
+        }
+
+        Some(Self {
+            year: year?,
+            month,
+            day: day.filter(|_| month.is_some()),
+        })
+    }
+}
+
+/// The position of a month named in full or abbreviated, e.g. "January", "Jan." or "Sept",
+/// so words like "Marble" or "Decision" are not taken for a month.
+fn month_position(token: &str) -> Option<usize> {
+    let word = token.trim_end_matches('.').to_lowercase();
+
+    MONTHS.iter().position(|month| {
+        *month == word
+            || (word.len() == 3 && month.starts_with(&word))
+            || (word == "sept" && *month == "september")
+    })
+}
+
+impl FromStr for PartialDate {
+    type Err = MetaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(date) = DateIso8601::from_str(s) {
+            return Ok(date.into());
+        }
+
+        // e.g. "Tue, 10 Jun 2003 04:00:00 GMT" of RSS feeds
+        if let Ok(date_time) = DateTime::parse_from_rfc2822(s.trim()) {
+            return Ok(DateIso8601 { date_time }.into());
+        }
+
+        // strip a trailing time like in "2019-06-15T10:00:00" or "2019-06-15 10:00"
+        let date = s.trim().split(['T', ' ']).next().unwrap_or_default();
+        let mut parts = date.split(['-', '/', '.']);
+
+        let year = parts
+            .next()
+            .filter(|year| year.len() == 4)
+            .and_then(|year| year.parse::<i32>().ok())
+            .ok_or(MetaError::PartialDateParse)?;
+        let month = parts.next().map(str::parse::<u8>).transpose()?;
+        let day = parts.next().map(str::parse::<u8>).transpose()?;
+
+        if month.is_some_and(|month| !(1..=12).contains(&month))
+            || day.is_some_and(|day| !(1..=31).contains(&day))
+        {
+            return Err(MetaError::PartialDateParse);
+        }
+
+        Ok(Self { year, month, day })
+    }
+}
+
+impl From<DateIso8601> for PartialDate {
+    fn from(date_iso_8601: DateIso8601) -> Self {
+        let date_time = date_iso_8601.date_time;
+        Self {
+            year: date_time.year(),
+            month: Some(date_time.month() as u8),
+            day: Some(date_time.day() as u8),
+        }
+    }
+}
+
+impl From<PartialDate> for Date {
+    fn from(date: PartialDate) -> Self {
+        Date {
+            day: date.day.map(|day| day - 1),
+            month: date.month.map(|month| month - 1),
+            year: date.year,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PartialDate;
+
+    #[test]
+    fn partial_date_parse() {
+        let date = "2019/06/15".parse::<PartialDate>().unwrap();
+        assert_eq!(
+            date,
+            PartialDate {
+                year: 2019,
+                month: Some(6),
+                day: Some(15)
+            }
+        );
+
+        let date = "2019".parse::<PartialDate>().unwrap();
+        assert_eq!(
+            date,
+            PartialDate {
+                year: 2019,
+                month: None,
+                day: None
+            }
+        );
+
+        let date = "2019-06-15T10:00:00+02:00".parse::<PartialDate>().unwrap();
+        assert_eq!(date.day, Some(15));
+
+        let date = "Sat, 15 Jun 2019 10:00:00 GMT"
+            .parse::<PartialDate>()
+            .unwrap();
+        assert_eq!(date.month, Some(6));
+
+        assert!("15.06.2019".parse::<PartialDate>().is_err());
+    }
+
+    #[test]
+    fn partial_date_from_words() {
+        let date = PartialDate {
+            year: 2021,
+            month: Some(1),
+            day: Some(5),
+        };
+
+        assert_eq!(
+            PartialDate::from_words("Tue Jan  5 10:00:00 CET 2021"),
+            Some(date)
+        );
+        assert_eq!(
+            PartialDate::from_words("Tuesday, 5 January 2021 10:00:00"),
+            Some(date)
+        );
+        assert_eq!(
+            PartialDate::from_words("Sept. 5, 2021").and_then(|date| date.month),
+            Some(9)
+        );
+    }
+
+    #[test]
+    fn partial_date_from_other_words() {
+        // words starting like a month are no month
+        let date = PartialDate::from_words("Marble Mayor Junior Decision 2021").unwrap();
+        assert_eq!(date.month, None);
+
+        // a day of 0 would underflow converting it to a hayagriva date
+        let date = PartialDate::from_words("00 January 2021").unwrap();
+        assert_eq!(date.month, Some(1));
+        assert_eq!(date.day, None);
+        assert_eq!(PartialDate::from_words("32 May 2021").unwrap().day, None);
+    }
+}
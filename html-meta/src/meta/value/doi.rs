@@ -0,0 +1,41 @@
+use std::str::FromStr;
+
+use crate::error::MetaError;
+
+/// A Digital Object Identifier like "10.5281/zenodo.1234",
+/// which may also be given as "doi:10.5281/zenodo.1234" or "https://doi.org/10.5281/zenodo.1234".
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Doi {
+    data: String,
+}
+
+impl FromStr for Doi {
+    type Err = MetaError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let data = [
+            "https://doi.org/",
+            "http://doi.org/",
+            "https://dx.doi.org/",
+            "doi:",
+        ]
+        .into_iter()
+        .find_map(|prefix| text.strip_prefix(prefix))
+        .unwrap_or(text);
+
+        if data.starts_with("10.") && data.contains('/') {
+            Ok(Self {
+                data: data.to_owned(),
+            })
+        } else {
+            Err(MetaError::DoiParse)
+        }
+    }
+}
+
+impl From<Doi> for String {
+    fn from(doi: Doi) -> Self {
+        doi.data
+    }
+}
@@ -4,9 +4,12 @@ use mime::Mime;
 use unic_langid::LanguageIdentifier;
 use url::Url;
 
-pub use date::DateIso8601;
+pub use date::{DateIso8601, PartialDate};
+pub use doi::Doi;
 pub use issn::Issn;
 pub use name::Name;
+pub use pages::PageRange;
+pub use runtime::Runtime;
 pub use title::Title;
 
 use crate::error::MetaError;
@@ -19,8 +22,11 @@ pub fn into_qualified(value: Url) -> QualifiedUrl {
 }
 
 mod date;
+mod doi;
 mod issn;
 mod name;
+mod pages;
+mod runtime;
 mod title;
 
 #[derive(Debug, Default)]
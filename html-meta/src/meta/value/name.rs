@@ -13,6 +13,19 @@ pub struct Name {
     pub alias: Option<String>,
 }
 
+impl Name {
+    /// A name that is used as is, e.g. for organizations.
+    pub fn literal(name: impl Into<String>) -> Self {
+        Self {
+            surname: name.into(),
+            given_name: None,
+            prefix: None,
+            suffix: None,
+            alias: None,
+        }
+    }
+}
+
 impl FromStr for Name {
     type Err = MetaError;
 
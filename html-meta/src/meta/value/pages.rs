@@ -0,0 +1,41 @@
+use std::{ops::Range, str::FromStr};
+
+use crate::error::MetaError;
+
+/// The pages a work occupies within its parent, e.g. "123-145".
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PageRange {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl PageRange {
+    pub fn new(first: &str, last: Option<&str>) -> Result<Self, MetaError> {
+        let start = first.trim().parse()?;
+        let end = match last {
+            Some(last) => last.trim().parse()?,
+            None => start,
+        };
+
+        Ok(Self { start, end })
+    }
+}
+
+impl FromStr for PageRange {
+    type Err = MetaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut pages = s
+            .split(['-', '–', '—'])
+            .filter(|page| !page.trim().is_empty());
+        let first = pages.next().ok_or(MetaError::PageRangeParse)?;
+
+        Self::new(first, pages.next())
+    }
+}
+
+impl From<PageRange> for Range<i64> {
+    fn from(pages: PageRange) -> Self {
+        pages.start..pages.end
+    }
+}
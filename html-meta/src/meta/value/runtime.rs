@@ -0,0 +1,101 @@
+use std::{fmt, str::FromStr};
+
+use crate::error::MetaError;
+
+/// The playing time of a video or an audio recording, given e.g. as ISO 8601 duration "PT1H4M13S",
+/// as clock time "1:04:13" or in seconds "3853".
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Runtime {
+    pub seconds: u64,
+}
+
+impl FromStr for Runtime {
+    type Err = MetaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Some(duration) = s.strip_prefix('P') {
+            return parse_iso_8601(duration);
+        }
+
+        let mut seconds = 0;
+        for part in s.split(':') {
+            seconds = seconds * 60 + part.parse::<u64>()?;
+        }
+
+        Ok(Self { seconds })
+    }
+}
+
+/// Formats the runtime as clock time like "01:04:13", which hayagriva parses its durations from.
+impl fmt::Display for Runtime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let days = self.seconds / 86400;
+        let hours = self.seconds / 3600 % 24;
+        let minutes = self.seconds / 60 % 60;
+        let seconds = self.seconds % 60;
+
+        if days > 0 {
+            write!(f, "{days}:{hours:02}:{minutes:02}:{seconds:02}")
+        } else {
+            write!(f, "{hours:02}:{minutes:02}:{seconds:02}")
+        }
+    }
+}
+
+// e.g. "T1H4M13S" or "1DT2H" after the leading "P"
+fn parse_iso_8601(duration: &str) -> Result<Runtime, MetaError> {
+    let mut seconds = 0.0;
+    let mut number = String::new();
+    let mut is_time = false;
+
+    for c in duration.chars() {
+        match c {
+            'T' => is_time = true,
+            '0'..='9' | '.' => number.push(c),
+            // the decimal comma of e.g. "PT13,5S"
+            ',' => number.push('.'),
+            unit => {
+                let value = number.parse::<f64>().map_err(|_| MetaError::RuntimeParse)?;
+                let factor = match (unit, is_time) {
+                    ('W', false) => 604800.0,
+                    ('D', false) => 86400.0,
+                    ('H', true) => 3600.0,
+                    ('M', true) => 60.0,
+                    ('S', true) => 1.0,
+                    // years and months have no fixed length
+                    _ => return Err(MetaError::RuntimeParse),
+                };
+
+                seconds += value * factor;
+                number.clear();
+            }
+        }
+    }
+
+    if !number.is_empty() {
+        return Err(MetaError::RuntimeParse);
+    }
+
+    Ok(Runtime {
+        seconds: seconds.round() as u64,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::Runtime;
+
+    #[test]
+    fn runtime_parse() {
+        let runtime = "PT1H4M13S".parse::<Runtime>().unwrap();
+        assert_eq!(runtime.seconds, 3853);
+        assert_eq!(runtime.to_string(), "01:04:13");
+
+        assert_eq!("PT00H04M13.5S".parse::<Runtime>().unwrap().seconds, 254);
+        assert_eq!("4:13".parse::<Runtime>().unwrap().seconds, 253);
+        assert_eq!("253".parse::<Runtime>().unwrap().seconds, 253);
+        assert!("P1M".parse::<Runtime>().is_err());
+    }
+}
@@ -1,30 +1,309 @@
-use crate::{error::MetaResult, meta::value::Title};
-use scraper::{Html, Selector};
+use crate::{
+    error::MetaResult,
+    meta::value::{Name, Title},
+};
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+use std::{collections::HashSet, sync::OnceLock};
 
+// e.g. "Title | Site", "Site — Title" or "Title · Site"
+const SEPARATORS: &[&str] = &[" | ", " — ", " – ", " - ", " · ", " :: ", " » ", " : "];
+
+/// The title of the page by its heading and its `<title>`, stripped of the name of the site.
 pub fn find_title(html: &Html) -> MetaResult<Option<String>> {
-    // TODO selector for <title>
-    let selector = Selector::parse("h1")?;
+    let h1 = first_text(html, "h1")?;
+    let title = first_text(html, "title")?;
+    let site_name = first_content(
+        html,
+        "meta[property=\"og:site_name\"], meta[name=\"application-name\"]",
+    )?;
 
-    let title = html
-        .select(&selector)
-        .next()
-        .map(|el| el.text().next())
+    let stripped = title
+        .as_deref()
+        .and_then(|title| strip_site_name(title, site_name.as_deref(), h1.as_deref()));
+
+    let title = match (h1, stripped) {
+        (Some(h1), Some(stripped)) if similarity(&h1, &stripped) >= 0.5 => Some(stripped),
+        // the stripped part was the title, e.g. of "Site - Title" without a known site name
+        (Some(h1), Some(_)) if title.is_some_and(|title| title.contains(&h1)) => Some(h1),
+        // otherwise the heading is e.g. the name of the site in its header
+        (h1, stripped) => stripped.or(h1),
+    };
+
+    Ok(title)
+}
+
+/// Strips the name of the site from the start or the end of a title like "Title | Site".
+/// Without a known name the part most similar to the heading is kept, or else the longer end.
+fn strip_site_name(title: &str, site_name: Option<&str>, h1: Option<&str>) -> Option<String> {
+    let title = title.trim();
+    let splits = SEPARATORS
+        .iter()
+        .filter_map(|separator| {
+            let (before, site) = title.rsplit_once(separator)?;
+            let (prefix, after) = title.split_once(separator)?;
+            Some([(before.trim(), site.trim()), (after.trim(), prefix.trim())])
+        })
         .flatten()
+        .filter(|(rest, site)| !rest.is_empty() && !site.is_empty())
+        .collect::<Vec<_>>();
+
+    let stripped = if let Some(site_name) = site_name {
+        splits
+            .iter()
+            .find(|(_, site)| similarity(site, site_name) >= 0.5)
+            .map(|(rest, _)| *rest)
+    } else if let Some(h1) = h1 {
+        splits
+            .iter()
+            .map(|(rest, _)| *rest)
+            .filter(|rest| similarity(rest, h1) >= 0.5)
+            .max_by(|a, b| similarity(a, h1).total_cmp(&similarity(b, h1)))
+    } else {
+        // the name of the site is usually appended to the title
+        splits
+            .first()
+            .filter(|(rest, site)| site.len() < rest.len())
+            .map(|(rest, _)| *rest)
+    };
+
+    let title = stripped.unwrap_or(title);
+    if site_name.is_some_and(|site_name| similarity(title, site_name) == 1.0) {
+        None
+    } else {
+        Some(title.to_owned()).filter(|title| !title.is_empty())
+    }
+}
+
+/// The share of words two texts have in common, from 0 to 1.
+fn similarity(a: &str, b: &str) -> f64 {
+    let words = |text: &str| {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect::<HashSet<_>>()
+    };
+    let (a, b) = (words(a), words(b));
+
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+fn first_text(html: &Html, selector: &'static str) -> MetaResult<Option<String>> {
+    let selector = Selector::parse(selector)?;
+
+    let text = html
+        .select(&selector)
+        .map(text)
+        .find(|text| !text.is_empty());
+    Ok(text)
+}
+
+fn first_content(html: &Html, selector: &'static str) -> MetaResult<Option<String>> {
+    let selector = Selector::parse(selector)?;
+
+    let content = html
+        .select(&selector)
+        .filter_map(|el| el.value().attr("content"))
+        .map(str::trim)
+        .find(|content| !content.is_empty())
         .map(ToOwned::to_owned);
-    Ok(title)
+    Ok(content)
+}
+
+fn text(el: ElementRef) -> String {
+    el.text()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+static BYLINE_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// How certain it is that a name of a byline is one of the authors of the page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Confidence {
+    /// A line like "By Jane Doe" near the heading.
+    Low,
+    /// An element classed as byline or author, or the address of an article.
+    Medium,
+    /// An author marked up with `rel="author"` or `itemprop="author"`.
+    High,
+}
+
+/// A name found in the visible byline of a page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorCandidate {
+    pub name: Name,
+    pub confidence: Confidence,
+}
+
+/// The authors named in the bylines of a page, the most certain first.
+pub fn find_authors(html: &Html) -> MetaResult<Vec<AuthorCandidate>> {
+    let heading_selector = Selector::parse("h1")?;
+    let article_selector = Selector::parse("main article, article")?;
+
+    // the bylines of the page itself, opposed to those of teasers or comments,
+    // are within the article of its heading or the header around its heading
+    let heading = html.select(&heading_selector).next();
+    let header = heading
+        .and_then(|heading| heading.parent())
+        .and_then(ElementRef::wrap);
+    let article = heading
+        .and_then(|heading| {
+            heading
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .find(|ancestor| ancestor.value().name() == "article")
+        })
+        .or_else(|| html.select(&article_selector).next());
+    let scope = article.or(header).unwrap_or(html.root_element());
+
+    // only the ancestors within the scope, as e.g. the body may be classed "comments-open"
+    let is_comment = |el: &ElementRef| {
+        el.ancestors()
+            .take_while(|ancestor| ancestor.id() != scope.id())
+            .filter_map(ElementRef::wrap)
+            .any(|ancestor| {
+                let ancestor = ancestor.value();
+                ancestor.attr("itemprop") == Some("comment")
+                    || ancestor.classes().any(|class| class.contains("comment"))
+            })
+    };
+
+    // all authors marked up as such are kept, of other bylines only the first one
+    let sources = [
+        (
+            "[itemprop=\"author\"] [itemprop=\"name\"], [itemprop=\"author\"], a[rel~=\"author\"]",
+            Confidence::High,
+            usize::MAX,
+        ),
+        (
+            ".byline, [class*=\"byline\"], .author, [class*=\"author-name\"]",
+            Confidence::Medium,
+            1,
+        ),
+        ("address", Confidence::Medium, 1),
+    ];
+
+    let mut bylines = Vec::new();
+    for (selector, confidence, count) in sources {
+        let selector = Selector::parse(selector)?;
+        bylines.extend(
+            scope
+                .select(&selector)
+                .filter(|el| !is_comment(el))
+                .take(count)
+                .map(|el| (text(el), confidence)),
+        );
+    }
+
+    // e.g. "By Jane Doe and John Roe" below the heading
+    let line_selector = Selector::parse("p, span, div")?;
+    if let Some(header) = header {
+        bylines.extend(
+            header
+                .select(&line_selector)
+                .map(text)
+                .filter(|line| line.len() < 120 && byline(line).is_some())
+                .take(1)
+                .map(|line| (line, Confidence::Low)),
+        );
+    }
+
+    let mut candidates: Vec<(String, Confidence)> = Vec::new();
+    for (line, confidence) in bylines {
+        let line = byline(&line).unwrap_or(line.as_str()).to_owned();
+
+        for name in split_names(&line) {
+            match candidates
+                .iter_mut()
+                .find(|(candidate, _)| candidate.to_lowercase() == name.to_lowercase())
+            {
+                Some((_, known)) => *known = (*known).max(confidence),
+                None => candidates.push((name, confidence)),
+            }
+        }
+    }
+    candidates.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+    let candidates = candidates
+        .into_iter()
+        .map(|(name, confidence)| AuthorCandidate {
+            name: name.parse().unwrap_or_else(|_| Name::literal(&name)),
+            confidence,
+        })
+        .collect();
+
+    Ok(candidates)
+}
+
+// the roles bylines often name along with the author, e.g. "Jane Doe, Staff Writer"
+const ROLES: &[&str] = &[
+    "staff",
+    "writer",
+    "editor",
+    "reporter",
+    "correspondent",
+    "contributor",
+    "author",
+];
+
+// e.g. "Jane Doe and John Roe" of "By Jane Doe and John Roe"
+fn byline(line: &str) -> Option<&str> {
+    let regex = BYLINE_REGEX.get_or_init(|| Regex::new(r"^(?i:by|von|par|door|di)\s+").unwrap());
+    let prefix = regex.find(line)?;
+
+    Some(&line[prefix.end()..])
+}
+
+/// The names of a byline like "Jane Doe, John Roe and Max Mustermann | Updated 3 May 2024",
+/// leaving out everything that does not look like the name of a person.
+fn split_names(byline: &str) -> Vec<String> {
+    // the date or the role often follows the names
+    let names = byline
+        .split(['|', '•', '·', '—', '–', '\n'])
+        .next()
+        .unwrap_or_default();
+    let names = names.split(" on ").next().unwrap_or_default();
+
+    names
+        .split([',', '&', ';'])
+        .flat_map(|names| names.split(" and "))
+        .map(|name| name.trim().trim_end_matches('.').to_owned())
+        .filter(|name| {
+            let words = name.split_whitespace().collect::<Vec<_>>();
+            (2..=4).contains(&words.len())
+                && !words
+                    .iter()
+                    .any(|word| ROLES.contains(&word.to_lowercase().as_str()))
+                && words.iter().all(|word| {
+                    word.starts_with(char::is_uppercase)
+                        && !word.contains(|c: char| c.is_ascii_digit())
+                })
+        })
+        .collect()
 }
 
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct HtmlQueryReport {
     pub title: Option<Title>,
+    pub authors: Vec<AuthorCandidate>,
 }
 
 impl HtmlQueryReport {
     pub fn from(html: &Html) -> MetaResult<Self> {
         let title = find_title(html)?;
         let title = title.map(Title::from);
+        let authors = find_authors(html)?;
 
-        Ok(Self { title })
+        Ok(Self { title, authors })
     }
 }
 
@@ -34,7 +313,7 @@ mod test {
 
     use crate::meta::value::Title;
 
-    use super::HtmlQueryReport;
+    use super::{find_authors, split_names, strip_site_name, Confidence, HtmlQueryReport};
 
     const EXAMPLE_HTML: &'static str = r#"<html>
     <head>
@@ -90,7 +369,82 @@ mod test {
 
         let expected = HtmlQueryReport {
             title: Some(Title::new("Example Domain")),
+            authors: Vec::new(),
         };
         assert_eq!(report, expected);
     }
+
+    #[test]
+    fn site_name_stripping() {
+        assert_eq!(
+            strip_site_name(
+                "Rust 1.75 released | The Rust Blog",
+                Some("Rust Blog"),
+                None
+            )
+            .as_deref(),
+            Some("Rust 1.75 released")
+        );
+        assert_eq!(
+            strip_site_name(
+                "Example News — Storm hits coast",
+                None,
+                Some("Storm hits the coast")
+            )
+            .as_deref(),
+            Some("Storm hits coast")
+        );
+        assert_eq!(
+            strip_site_name("Storm hits coast - Example News", None, None).as_deref(),
+            Some("Storm hits coast")
+        );
+        assert_eq!(
+            strip_site_name("Example News", Some("Example News"), None),
+            None
+        );
+    }
+
+    #[test]
+    fn byline_names() {
+        assert_eq!(
+            split_names("Jane Doe and John Roe | Updated 3 May 2024"),
+            vec!["Jane Doe".to_owned(), "John Roe".to_owned()]
+        );
+        assert_eq!(
+            split_names("Jane Doe, Staff Writer"),
+            vec!["Jane Doe".to_owned()]
+        );
+        assert_eq!(split_names("the editorial team"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn authors_of_main_article() {
+        let html = Html::parse_document(
+            r#"<html><body>
+            <article class="post comments-open">
+                <header>
+                    <h1>Storm hits the coast</h1>
+                    <p class="byline">By Jane Doe and John Roe</p>
+                </header>
+                <p>The storm reached the coast on Monday.</p>
+                <section class="comments">
+                    <div class="comment"><span class="author">Max Mustermann</span></div>
+                </section>
+            </article>
+            <aside>
+                <article><p class="byline">By Erika Musterfrau</p></article>
+            </aside>
+        </body></html>"#,
+        );
+        let authors = find_authors(&html).unwrap();
+
+        let names = authors
+            .iter()
+            .map(|author| author.name.surname.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["Doe", "Roe"]);
+        assert!(authors
+            .iter()
+            .all(|author| author.confidence == Confidence::Medium));
+    }
 }
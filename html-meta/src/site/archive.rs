@@ -0,0 +1,332 @@
+use crate::{
+    dom::Dom,
+    error::MetaResult,
+    meta::value::{Name, PartialDate},
+    site::artwork::creation_date,
+};
+use regex::Regex;
+use scraper::{ElementRef, Selector};
+use std::sync::OnceLock;
+use url::Url;
+
+// the elements of an EAD finding aid, whose names are kept as classes when rendered as HTML
+const EAD_ELEMENTS: &[(&str, &str)] = &[
+    ("title", ".unittitle, .ead-unittitle"),
+    ("creator", ".origination, .ead-origination"),
+    ("date", ".unitdate, .ead-unitdate"),
+    ("repository", ".did .repository, .ead-repository"),
+    ("call number", ".unitid, .ead-unitid"),
+    ("container", ".did .container, .ead-container"),
+];
+
+// the records of the public interface of ArchivesSpace, e.g. "/repositories/2/resources/55"
+static ARCHIVES_SPACE_REGEX: OnceLock<Regex> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchivalSource {
+    /// A finding aid encoded in EAD and rendered as HTML.
+    Ead,
+    /// A collection or an item of the public interface of ArchivesSpace.
+    ArchivesSpace,
+    /// The viewer of a digitised manuscript which links its IIIF manifest.
+    Iiif,
+}
+
+/// A manuscript, a letter or another unpublished item kept in an archive.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ArchivalRecord {
+    pub source: ArchivalSource,
+    pub title: Option<String>,
+    pub creators: Vec<Name>,
+    pub date: Option<PartialDate>,
+    /// The archive or library holding the item.
+    pub repository: Option<String>,
+    /// The collection the item belongs to, e.g. "Papers of Jane Doe".
+    pub collection: Option<String>,
+    /// The call number or shelfmark, e.g. "MS 408".
+    pub call_number: Option<String>,
+    /// The box and folder of the item, e.g. "Box 3, Folder 2".
+    pub container: Option<String>,
+    pub manifest: Option<Url>,
+    pub url: Url,
+}
+
+impl ArchivalRecord {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let manifest = manifest(dom)?;
+
+        let (source, fields) = if let Some(fields) = archives_space(dom)? {
+            (ArchivalSource::ArchivesSpace, fields)
+        } else if let Some(fields) = ead(dom)? {
+            (ArchivalSource::Ead, fields)
+        } else if manifest.is_some() {
+            (ArchivalSource::Iiif, description_list(dom)?)
+        } else {
+            return Ok(None);
+        };
+
+        let mut url = dom.url().clone();
+        url.set_fragment(None);
+
+        let mut record = Self {
+            source,
+            title: None,
+            creators: Vec::new(),
+            date: None,
+            repository: None,
+            collection: None,
+            call_number: None,
+            container: None,
+            manifest,
+            url,
+        };
+        for (label, value) in fields {
+            record.push(&label, value);
+        }
+
+        if record.title.is_none() {
+            record.title = dom.text("h1")?;
+        }
+
+        Ok(Some(record))
+    }
+
+    /// Adds a labeled field like "Shelfmark: MS 408" of a finding aid or a viewer.
+    fn push(&mut self, label: &str, value: String) {
+        let label = label.trim().trim_end_matches(':').to_lowercase();
+        let value = value.trim().to_owned();
+        if value.is_empty() {
+            return;
+        }
+
+        match label.as_str() {
+            "title" => {
+                self.title.get_or_insert(value);
+            }
+            "creator" | "author" | "origination" => {
+                self.creators
+                    .push(value.parse().unwrap_or_else(|_| Name::literal(&value)));
+            }
+            "date" | "dates" | "date created" => {
+                self.date = self.date.take().or(creation_date(&value));
+            }
+            "repository" | "holding institution" | "library" | "archive" => {
+                self.repository.get_or_insert(value);
+            }
+            "collection" | "part of" => {
+                self.collection.get_or_insert(value);
+            }
+            "call number" | "shelfmark" | "signature" | "identifier" | "reference code" => {
+                self.call_number.get_or_insert(value);
+            }
+            "container" | "location" | "physical location" => {
+                self.container.get_or_insert(value);
+            }
+            _ => (),
+        }
+    }
+
+    /// The location of the item within its archive, e.g. "Papers of Jane Doe, MS 408, Box 3".
+    pub fn archive_location(&self) -> Option<String> {
+        let parts = [&self.collection, &self.call_number, &self.container]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+
+        Some(parts.join(", ")).filter(|location| !location.is_empty())
+    }
+}
+
+fn ead(dom: &Dom) -> MetaResult<Option<Vec<(String, String)>>> {
+    let mut fields = Vec::new();
+
+    for (label, selector) in EAD_ELEMENTS {
+        let selector = Selector::parse(selector)?;
+        if let Some(el) = dom.html().select(&selector).next() {
+            fields.push((label.to_string(), text(el)));
+        }
+    }
+
+    // the title and the call number identify the unit of description
+    let is_ead = fields
+        .iter()
+        .any(|(label, _)| label == "title" || label == "call number");
+
+    Ok(Some(fields).filter(|_| is_ead))
+}
+
+fn archives_space(dom: &Dom) -> MetaResult<Option<Vec<(String, String)>>> {
+    let regex = ARCHIVES_SPACE_REGEX.get_or_init(|| {
+        Regex::new(r"^/repositories/\d+/(?:resources|archival_objects|digital_objects)/\d+")
+            .unwrap()
+    });
+    if !regex.is_match(dom.url().path()) {
+        return Ok(None);
+    }
+
+    let mut fields = Vec::new();
+    let mut push = |label: &str, value: Option<String>| {
+        if let Some(value) = value {
+            fields.push((label.to_owned(), value));
+        }
+    };
+
+    push(
+        "title",
+        match dom.text("#main-content h1")? {
+            Some(title) => Some(title),
+            None => dom.text("h1")?,
+        },
+    );
+    push("identifier", dom.text(".identifier .component")?);
+    push(
+        "dates",
+        match dom.text(".dates li")? {
+            Some(dates) => Some(dates),
+            None => dom.text(".dates")?,
+        },
+    );
+
+    let creator_selector = Selector::parse(".agents_list a[href*=\"/agents/\"]")?;
+    for creator in dom.html().select(&creator_selector) {
+        push("creator", Some(text(creator)));
+    }
+
+    // e.g. "Special Collections > Collection Papers of Jane Doe > Series 1"
+    let breadcrumb_selector = Selector::parse(".breadcrumb a[href]")?;
+    let badge_selector = Selector::parse(".record-type-badge")?;
+    for link in dom.html().select(&breadcrumb_selector) {
+        let href = link.value().attr("href").unwrap_or_default();
+        let badge = link.select(&badge_selector).next().map(text);
+        let name = text(link);
+        let name = match &badge {
+            Some(badge) => name.trim_start_matches(badge.as_str()).trim().to_owned(),
+            None => name,
+        };
+
+        if href.trim_end_matches('/').rsplit('/').nth(1) == Some("repositories") {
+            push("repository", Some(name));
+        } else if href.contains("/resources/") && href != dom.url().path() {
+            push("collection", Some(name));
+        }
+    }
+
+    Ok(Some(fields))
+}
+
+/// The metadata viewers list next to the images, e.g. "<dt>Shelfmark</dt><dd>MS 408</dd>".
+fn description_list(dom: &Dom) -> MetaResult<Vec<(String, String)>> {
+    let selector = Selector::parse("dl dt")?;
+
+    let fields = dom
+        .html()
+        .select(&selector)
+        .filter_map(|term| {
+            let description = term
+                .next_siblings()
+                .filter_map(ElementRef::wrap)
+                .next()
+                .filter(|el| el.value().name() == "dd")?;
+            Some((text(term), text(description)))
+        })
+        .collect();
+
+    Ok(fields)
+}
+
+fn manifest(dom: &Dom) -> MetaResult<Option<Url>> {
+    let href = match dom.attr(
+        "link[rel=\"alternate\"][href*=\"manifest\"], a[href*=\"manifest.json\"], \
+         a[href*=\"iiif\"][href*=\"manifest\"]",
+        "href",
+    )? {
+        Some(href) => Some(href),
+        None => dom.attr("[data-manifest]", "data-manifest")?,
+    };
+
+    Ok(href.and_then(|href| dom.url().join(&href).ok()))
+}
+
+fn text(el: ElementRef) -> String {
+    el.text()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ArchivalRecord, ArchivalSource};
+    use crate::dom::Dom;
+
+    #[test]
+    fn archive_location() {
+        let mut record = ArchivalRecord {
+            source: ArchivalSource::Iiif,
+            title: None,
+            creators: Vec::new(),
+            date: None,
+            repository: None,
+            collection: None,
+            call_number: None,
+            container: None,
+            manifest: None,
+            url: "https://example.org/ms-408".parse().unwrap(),
+        };
+        assert_eq!(record.archive_location(), None);
+
+        record.push("Shelfmark:", "MS 408".to_owned());
+        record.push("Collection", "Papers of Jane Doe".to_owned());
+        record.push("Date", "ca. 1450".to_owned());
+        assert_eq!(
+            record.archive_location().as_deref(),
+            Some("Papers of Jane Doe, MS 408")
+        );
+        assert_eq!(record.date.map(|date| date.year), Some(1450));
+    }
+
+    #[test]
+    fn archives_space_record() {
+        let html = r#"<html><body>
+            <ol class="breadcrumb">
+                <li><a href="/repositories/2">Special Collections</a></li>
+                <li><a href="/repositories/2/resources/55">
+                    <span class="record-type-badge resource">Collection</span>
+                    Papers of Jane Doe</a></li>
+                <li>Letter to John Roe</li>
+            </ol>
+            <div id="main-content">
+                <h1>Letter to John Roe</h1>
+                <div class="identifier">
+                    <span class="id-label">Identifier:</span>
+                    <span class="component">MS 408</span>
+                </div>
+                <h3>Dates</h3>
+                <ul class="dates"><li class="dates">1850 May 3</li></ul>
+                <h3>Creator</h3>
+                <ul class="present_list agents_list">
+                    <li><a href="/agents/people/12">Doe, Jane</a></li>
+                </ul>
+            </div>
+        </body></html>"#;
+        let dom = Dom::parse(
+            html,
+            "https://archives.example.org/repositories/2/archival_objects/4711",
+        )
+        .unwrap();
+        let record = ArchivalRecord::detect(&dom).unwrap().unwrap();
+
+        assert_eq!(record.source, ArchivalSource::ArchivesSpace);
+        assert_eq!(record.title.as_deref(), Some("Letter to John Roe"));
+        assert_eq!(record.creators.len(), 1);
+        assert_eq!(record.date.map(|date| date.year), Some(1850));
+        assert_eq!(record.repository.as_deref(), Some("Special Collections"));
+        assert_eq!(
+            record.archive_location().as_deref(),
+            Some("Papers of Jane Doe, MS 408")
+        );
+    }
+}
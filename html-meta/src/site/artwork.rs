@@ -0,0 +1,202 @@
+use crate::{
+    dom::Dom,
+    error::MetaResult,
+    meta::value::{Name, PartialDate},
+};
+use regex::Regex;
+use std::sync::OnceLock;
+use url::Url;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Collection {
+    /// A file page of Wikimedia Commons, described by its Artwork or Information template.
+    WikimediaCommons,
+    Europeana,
+    /// The collection of a museum published with eMuseum, e.g. "/objects/1234/title".
+    EMuseum,
+}
+
+impl Collection {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let url = dom.url();
+        let host = url.host_str().unwrap_or_default();
+
+        let collection = if host == "commons.wikimedia.org" && url.path().starts_with("/wiki/File:")
+        {
+            Self::WikimediaCommons
+        } else if host.ends_with("europeana.eu") && url.path().contains("/item/") {
+            Self::Europeana
+        } else if url.path().contains("/objects/") && dom.text(".detailField")?.is_some() {
+            Self::EMuseum
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(collection))
+    }
+
+    fn selectors(&self) -> CollectionSelectors {
+        match self {
+            // the labels of the template rows have ids, the values are in the following cell
+            Self::WikimediaCommons => CollectionSelectors {
+                title: "#fileinfotpl_art_title + td",
+                artist: "#fileinfotpl_creator + td .fn, #fileinfotpl_aut + td a",
+                date: "#fileinfotpl_date + td",
+                medium: "#fileinfotpl_art_medium + td",
+                dimensions: "#fileinfotpl_art_dimensions + td",
+                institution: "#fileinfotpl_art_gallery + td a, #fileinfotpl_art_gallery + td",
+                accession_number: "#fileinfotpl_art_id + td",
+            },
+            Self::Europeana => CollectionSelectors {
+                title: "[data-field-name=\"dcTitle\"] li, h1",
+                artist: "[data-field-name=\"dcCreator\"] li",
+                date: "[data-field-name=\"dctermsCreated\"] li, [data-field-name=\"dcDate\"] li",
+                medium: "[data-field-name=\"dctermsMedium\"] li",
+                dimensions:
+                    "[data-field-name=\"dctermsExtent\"] li, [data-field-name=\"dcFormat\"] li",
+                institution: "[data-field-name=\"edmDataProvider\"] li, \
+                              [data-field-name=\"dataProvider\"] li",
+                accession_number: "[data-field-name=\"dcIdentifier\"] li",
+            },
+            Self::EMuseum => CollectionSelectors {
+                title: ".titleField .detailFieldValue, .titleField h1, h1",
+                artist: ".peopleField a, .peopleField .detailFieldValue",
+                date: ".displayDateField .detailFieldValue",
+                medium: ".mediumField .detailFieldValue",
+                dimensions: ".dimensionsField .detailFieldValue",
+                institution: ".creditlineField .detailFieldValue",
+                accession_number: ".invnolineField .detailFieldValue",
+            },
+        }
+    }
+}
+
+struct CollectionSelectors {
+    title: &'static str,
+    artist: &'static str,
+    date: &'static str,
+    medium: &'static str,
+    dimensions: &'static str,
+    institution: &'static str,
+    accession_number: &'static str,
+}
+
+/// A painting, a photograph or another object of a museum or an archive.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Artwork {
+    pub collection: Collection,
+    pub title: Option<String>,
+    pub artist: Option<Name>,
+    pub created: Option<PartialDate>,
+    /// The material, e.g. "Oil on canvas".
+    pub medium: Option<String>,
+    /// The dimensions of the object, or of the digital image if nothing else is known,
+    /// e.g. "73.5 × 92 cm".
+    pub dimensions: Option<String>,
+    /// The museum or archive holding the object.
+    pub institution: Option<String>,
+    /// The number under which the institution has registered the object, e.g. "SK-C-5".
+    pub accession_number: Option<String>,
+    pub url: Url,
+}
+
+impl Artwork {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let collection = match Collection::detect(dom)? {
+            Some(collection) => collection,
+            None => return Ok(None),
+        };
+        let selectors = collection.selectors();
+        let image = dom.ogp_metadata()?.image;
+
+        // the alternative text describes the image the page is about
+        let title = match collection {
+            Collection::WikimediaCommons => dom.text(selectors.title)?.or(dom
+                .text("#firstHeading")?
+                .map(|heading| file_name(&heading))),
+            _ => dom.text(selectors.title)?,
+        }
+        .or(image.alt);
+
+        let dimensions = dom
+            .text(selectors.dimensions)?
+            .or(match (image.width, image.height) {
+                (Some(width), Some(height)) if collection == Collection::WikimediaCommons => {
+                    Some(format!("{width} × {height} px"))
+                }
+                _ => None,
+            });
+
+        let institution = match collection {
+            Collection::EMuseum => dom.meta_content("meta[property=\"og:site_name\"]")?,
+            _ => dom.text(selectors.institution)?,
+        };
+
+        let mut url = dom.url().clone();
+        url.set_query(None);
+        url.set_fragment(None);
+
+        Ok(Some(Self {
+            collection,
+            title,
+            artist: dom.text(selectors.artist)?.map(|artist| {
+                artist
+                    .parse()
+                    .unwrap_or_else(|_| Name::literal(artist.trim()))
+            }),
+            created: dom
+                .text(selectors.date)?
+                .and_then(|date| creation_date(&date)),
+            medium: dom.text(selectors.medium)?,
+            dimensions,
+            institution,
+            accession_number: dom.text(selectors.accession_number)?,
+            url,
+        }))
+    }
+}
+
+static YEAR_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// The date an object was created, which is often approximate, e.g. "c. 1665" or "1642–1643".
+pub fn creation_date(text: &str) -> Option<PartialDate> {
+    if let Ok(date) = text.parse() {
+        return Some(date);
+    }
+
+    let regex = YEAR_REGEX.get_or_init(|| Regex::new(r"\b(\d{4})\b").unwrap());
+    let year = regex.captures(text)?.get(1)?.as_str().parse().ok()?;
+
+    match PartialDate::from_words(text) {
+        Some(date) if date.year == year => Some(date),
+        _ => Some(PartialDate {
+            year,
+            month: None,
+            day: None,
+        }),
+    }
+}
+
+// e.g. "The Night Watch" of the heading "File:The Night Watch.jpg"
+fn file_name(heading: &str) -> String {
+    let file = heading.trim_start_matches("File:");
+
+    match file.rsplit_once('.') {
+        Some((name, _)) => name.to_owned(),
+        None => file.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::creation_date;
+
+    #[test]
+    fn approximate_creation_date() {
+        assert_eq!(creation_date("c. 1665").map(|date| date.year), Some(1665));
+        assert_eq!(creation_date("1642–1643").map(|date| date.year), Some(1642));
+
+        let date = creation_date("1 May 2015, 14:00:00").unwrap();
+        assert_eq!((date.year, date.month, date.day), (2015, Some(5), Some(1)));
+    }
+}
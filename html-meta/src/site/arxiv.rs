@@ -0,0 +1,144 @@
+use crate::{
+    dom::Dom,
+    error::MetaResult,
+    meta::value::{Doi, PartialDate},
+};
+use regex::Regex;
+use std::sync::OnceLock;
+use url::Url;
+
+/// The abstract page of an arXiv preprint, e.g. "https://arxiv.org/abs/2101.00001v3".
+#[derive(Debug, PartialEq, Eq)]
+pub struct ArxivPaper {
+    /// The identifier without version, e.g. "2101.00001" or "hep-th/9901001".
+    pub id: String,
+    /// The version the url points to, or otherwise the latest one.
+    pub version: Option<u32>,
+    /// The submission date of the version.
+    pub submitted: Option<PartialDate>,
+    /// The primary category, e.g. "cs.CL".
+    pub category: Option<String>,
+    /// The journal reference of the published paper, e.g. "Phys. Rev. D 103, 012345 (2021)".
+    pub journal_ref: Option<String>,
+    /// The DOI of the published paper, not the one arXiv assigns to the preprint itself.
+    pub doi: Option<Doi>,
+    /// The abstract page of the version.
+    pub url: Url,
+}
+
+impl ArxivPaper {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let url = dom.url();
+        let host = url.host_str().unwrap_or_default();
+        if !matches!(host, "arxiv.org" | "www.arxiv.org" | "export.arxiv.org") {
+            return Ok(None);
+        }
+
+        // old identifiers contain the archive, e.g. "/abs/hep-th/9901001v2"
+        let path = match url.path().strip_prefix("/abs/") {
+            Some(path) => path.trim_end_matches('/'),
+            None => return Ok(None),
+        };
+        let (id, version) = match dom.meta_content("meta[name=\"citation_arxiv_id\"]")? {
+            Some(id) => (id, split_version(path).1),
+            None => {
+                let (id, version) = split_version(path);
+                (id.to_owned(), version)
+            }
+        };
+
+        let history = dom
+            .text(".submission-history")?
+            .map(|history| versions(&history))
+            .unwrap_or_default();
+        let version = version.or(history.iter().map(|(version, _)| *version).max());
+        let submitted = history
+            .into_iter()
+            .find(|(other, _)| Some(*other) == version)
+            .map(|(_, date)| date);
+
+        // e.g. "Computation and Language (cs.CL)"
+        let category = dom.text(".primary-subject")?.and_then(|subject| {
+            let (_, category) = subject.rsplit_once('(')?;
+            Some(category.trim_end_matches(')').to_owned())
+        });
+        let doi = dom
+            .text("td.tablecell.doi a, td.tablecell.doi")?
+            .and_then(|doi| doi.parse().ok());
+
+        let url = Url::parse("https://arxiv.org/abs/")?.join(&versioned(&id, version))?;
+
+        Ok(Some(Self {
+            id,
+            version,
+            submitted,
+            category,
+            journal_ref: dom.text("td.tablecell.jref")?,
+            doi,
+            url,
+        }))
+    }
+
+    /// The identifier with its version, e.g. "2101.00001v3".
+    pub fn versioned_id(&self) -> String {
+        versioned(&self.id, self.version)
+    }
+}
+
+fn versioned(id: &str, version: Option<u32>) -> String {
+    match version {
+        Some(version) => format!("{id}v{version}"),
+        None => id.to_owned(),
+    }
+}
+
+/// Splits the version from an identifier like "2101.00001v3".
+fn split_version(id: &str) -> (&str, Option<u32>) {
+    if let Some((id, version)) = id.rsplit_once('v')
+        && let Ok(version) = version.parse()
+    {
+        (id, Some(version))
+    } else {
+        (id, None)
+    }
+}
+
+static VERSION_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// The versions of the submission history like "[v1] Fri, 1 Jan 2021 18:59:59 UTC (1,234 KB)".
+fn versions(history: &str) -> Vec<(u32, PartialDate)> {
+    let regex = VERSION_REGEX.get_or_init(|| Regex::new(r"\[v(\d+)\]\s*([^(\[]+)").unwrap());
+
+    regex
+        .captures_iter(history)
+        .filter_map(|captures| {
+            let version = captures.get(1)?.as_str().parse().ok()?;
+            // chrono only knows the "UT" and "GMT" of RFC 2822
+            let date = captures.get(2)?.as_str().trim().replace(" UTC", " GMT");
+            Some((version, date.parse().ok()?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{split_version, versions};
+
+    #[test]
+    fn submission_history() {
+        assert_eq!(split_version("2101.00001v3"), ("2101.00001", Some(3)));
+        assert_eq!(
+            split_version("solv-int/9901001"),
+            ("solv-int/9901001", None)
+        );
+
+        let history = "Submission history From: Jane Doe [view email] \
+            [v1] Fri, 1 Jan 2021 18:59:59 UTC (1,234 KB) \
+            [v3] Mon, 15 Mar 2021 09:00:00 UTC (1,300 KB)";
+        let versions = versions(history);
+
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[1].0, 3);
+        assert_eq!(versions[1].1.year, 2021);
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{dom::Dom, error::MetaResult, meta::value::Name};
+use regex::Regex;
+use scraper::Selector;
+use std::sync::OnceLock;
+
+static DATE_PATH_REGEX: OnceLock<Regex> = OnceLock::new();
+static PART_REGEX: OnceLock<Regex> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlogPlatform {
+    WordPress,
+    Ghost,
+    Hugo,
+    Jekyll,
+    Substack,
+    Medium,
+}
+
+impl BlogPlatform {
+    fn from_generator(generator: &str) -> Option<Self> {
+        let generator = generator.to_lowercase();
+
+        let platform = if generator.starts_with("wordpress") {
+            Self::WordPress
+        } else if generator.starts_with("ghost") {
+            Self::Ghost
+        } else if generator.starts_with("hugo") {
+            Self::Hugo
+        } else if generator.starts_with("jekyll") {
+            Self::Jekyll
+        } else if generator.starts_with("substack") {
+            Self::Substack
+        } else if generator.starts_with("medium") {
+            Self::Medium
+        } else {
+            return None;
+        };
+
+        Some(platform)
+    }
+
+    fn from_host(host: &str) -> Option<Self> {
+        let platform = if host.ends_with(".substack.com") {
+            Self::Substack
+        } else if host == "medium.com" || host.ends_with(".medium.com") {
+            Self::Medium
+        } else if host.ends_with(".wordpress.com") {
+            Self::WordPress
+        } else if host.ends_with(".ghost.io") {
+            Self::Ghost
+        } else {
+            return None;
+        };
+
+        Some(platform)
+    }
+
+    /// Recognises the platform by the paths it serves its assets and its API from,
+    /// e.g. "/wp-content/" of WordPress or "/content/images/" of Ghost.
+    fn from_layout(dom: &Dom) -> MetaResult<Option<Self>> {
+        let wordpress = Selector::parse(
+            "link[rel=\"https://api.w.org/\"], link[href*=\"/wp-content/\"], \
+             script[src*=\"/wp-content/\"], script[src*=\"/wp-includes/\"]",
+        )?;
+        let ghost = Selector::parse(
+            "script[data-ghost], script[src*=\"/ghost/\"], img[src*=\"/content/images/\"]",
+        )?;
+
+        let platform = if dom.html().select(&wordpress).next().is_some() {
+            Some(Self::WordPress)
+        } else if dom.html().select(&ghost).next().is_some() {
+            Some(Self::Ghost)
+        } else {
+            None
+        };
+
+        Ok(platform)
+    }
+}
+
+/// A multi-part series of posts, e.g. "Writing an OS in Rust, Part 3".
+#[derive(Debug, PartialEq, Eq)]
+pub struct BlogSeries {
+    pub name: Option<String>,
+    pub part: Option<i64>,
+}
+
+/// A post on a blog, whose name and owner make up the parent of the citation.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Blog {
+    pub platform: BlogPlatform,
+    pub name: Option<String>,
+    pub owner: Option<Name>,
+    pub series: Option<BlogSeries>,
+}
+
+impl Blog {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let url = dom.url();
+
+        // the front page of a blog is not a post
+        if url.path() == "/" {
+            return Ok(None);
+        }
+
+        let platform = match Self::detect_platform(dom)? {
+            Some(platform) => platform,
+            None => return Ok(None),
+        };
+
+        if !Self::is_post(dom, platform)? {
+            return Ok(None);
+        }
+
+        let name = dom.meta_content("meta[property=\"og:site_name\"]")?;
+
+        let mut owner = dom
+            .meta_content("meta[name=\"author\"]")?
+            .and_then(|author| author.parse::<Name>().ok());
+        // e.g. "https://medium.com/@handle/title-1234"
+        if platform == BlogPlatform::Medium
+            && let Some(owner) = &mut owner
+            && let Some(handle) = url.path_segments().and_then(|mut s| s.next())
+            && handle.starts_with('@')
+        {
+            owner.alias = Some(handle.to_owned());
+        }
+
+        let series = Self::detect_series(dom)?;
+
+        Ok(Some(Self {
+            platform,
+            name,
+            owner,
+            series,
+        }))
+    }
+
+    fn detect_platform(dom: &Dom) -> MetaResult<Option<BlogPlatform>> {
+        let generator = dom
+            .meta_content("meta[name=\"generator\"]")?
+            .and_then(|generator| BlogPlatform::from_generator(&generator));
+        if generator.is_some() {
+            return Ok(generator);
+        }
+
+        let host = dom.url().host_str().and_then(BlogPlatform::from_host);
+        if host.is_some() {
+            return Ok(host);
+        }
+
+        // the location of a feed like "/feed/" is shared by too many sites to tell the platform
+        BlogPlatform::from_layout(dom)
+    }
+
+    /// Whether the page is a single post, judging by its type or its url,
+    /// e.g. "/2023/05/12/title/".
+    fn is_post(dom: &Dom, platform: BlogPlatform) -> MetaResult<bool> {
+        if matches!(platform, BlogPlatform::Medium | BlogPlatform::Substack) {
+            return Ok(true);
+        }
+
+        let kind = dom.meta_content("meta[property=\"og:type\"]")?;
+        if kind.as_deref() == Some("article") {
+            return Ok(true);
+        }
+
+        let regex = DATE_PATH_REGEX.get_or_init(|| Regex::new(r"/\d{4}/\d{2}/").unwrap());
+        Ok(regex.is_match(dom.url().path()))
+    }
+
+    fn detect_series(dom: &Dom) -> MetaResult<Option<BlogSeries>> {
+        let title = match dom.meta_content("meta[property=\"og:title\"]")? {
+            Some(title) => Some(title),
+            None => {
+                let selector = Selector::parse("title")?;
+                dom.html()
+                    .select(&selector)
+                    .next()
+                    .map(|el| el.text().collect::<String>())
+            }
+        };
+        let part = title.as_deref().and_then(part_number);
+
+        // series taxonomies as used by Hugo and the WordPress series plugins
+        let selector = Selector::parse("a[href*=\"/series/\"], a[rel=\"series\"]")?;
+        let name = dom
+            .html()
+            .select(&selector)
+            .map(|el| el.text().collect::<String>().trim().to_owned())
+            .find(|name| !name.is_empty());
+
+        if part.is_none() && name.is_none() {
+            Ok(None)
+        } else {
+            Ok(Some(BlogSeries { name, part }))
+        }
+    }
+}
+
+/// Finds the part number in titles like "Async Rust, Part 2" or "Async Rust (pt. two)".
+fn part_number(title: &str) -> Option<i64> {
+    const NUMBERS: [&str; 10] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+
+    let regex =
+        PART_REGEX.get_or_init(|| Regex::new(r"(?i)\b(?:part|pt\.?)\s+(\d+|[a-z]+)\b").unwrap());
+    let part = regex.captures(title)?.get(1)?.as_str().to_lowercase();
+
+    part.parse().ok().or_else(|| {
+        NUMBERS
+            .iter()
+            .position(|number| *number == part)
+            .map(|position| position as i64 + 1)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{part_number, Blog, BlogPlatform};
+    use crate::dom::Dom;
+
+    #[test]
+    fn part_number_from_title() {
+        assert_eq!(part_number("Writing an OS in Rust, Part 3"), Some(3));
+        assert_eq!(part_number("Async Rust (pt. two)"), Some(2));
+        assert_eq!(part_number("Partial borrows in Rust"), None);
+        assert_eq!(part_number("Part of the problem"), None);
+    }
+
+    #[test]
+    fn platform_from_layout() {
+        let platform = |head: &str| {
+            let html = format!("<html><head>{head}</head></html>");
+            let dom = Dom::parse(&html, "https://example.org/2023/05/12/title/").unwrap();
+            Blog::detect_platform(&dom).unwrap()
+        };
+
+        assert_eq!(
+            platform(r#"<link rel="https://api.w.org/" href="https://example.org/wp-json/">"#),
+            Some(BlogPlatform::WordPress)
+        );
+        assert_eq!(
+            platform(r#"<meta name="generator" content="Hugo 0.120.4">"#),
+            Some(BlogPlatform::Hugo)
+        );
+        // a feed alone is no sign of any platform
+        assert_eq!(
+            platform(r#"<link rel="alternate" type="application/rss+xml" href="/feed/">"#),
+            None
+        );
+        assert_eq!(
+            platform(r#"<link rel="alternate" type="application/rss+xml" href="/rss/">"#),
+            None
+        );
+    }
+}
@@ -0,0 +1,448 @@
+use crate::{
+    dom::Dom,
+    error::MetaResult,
+    meta::value::{Name, PartialDate},
+};
+use isbn2::Isbn;
+use regex::Regex;
+use scraper::{ElementRef, Selector};
+use serde_json::Value;
+use std::sync::OnceLock;
+use url::Url;
+
+static EDITION_REGEX: OnceLock<Regex> = OnceLock::new();
+static PAGES_REGEX: OnceLock<Regex> = OnceLock::new();
+static VOLUMES_REGEX: OnceLock<Regex> = OnceLock::new();
+static SERIES_REGEX: OnceLock<Regex> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Catalogue {
+    OpenLibrary,
+    GoogleBooks,
+    WorldCat,
+    /// The page of a publisher or a book shop describing the book with schema.org microdata.
+    Publisher,
+}
+
+/// A single edition of a book as described by a library catalogue or a book shop.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CatalogueBook {
+    pub catalogue: Catalogue,
+    pub title: Option<String>,
+    pub authors: Vec<Name>,
+    pub editors: Vec<Name>,
+    pub publisher: Option<String>,
+    /// The place of publication, e.g. "London".
+    pub place: Option<String>,
+    /// The edition statement, e.g. "2" of "2nd edition" or "Revised edition".
+    pub edition: Option<String>,
+    pub date: Option<PartialDate>,
+    pub series: Option<String>,
+    /// The number of the book within its series.
+    pub series_number: Option<i64>,
+    /// The number of volumes if the edition is a multi-volume set.
+    pub volume_total: Option<i64>,
+    pub pages: Option<i64>,
+    /// The ISBNs of the edition, e.g. of its ISBN-10 and ISBN-13.
+    pub isbns: Vec<Isbn>,
+    pub url: Url,
+}
+
+impl CatalogueBook {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let host = dom.url().host_str().unwrap_or_default();
+
+        let (catalogue, fields) = if host.starts_with("books.google.") {
+            (Catalogue::GoogleBooks, metadata_table(dom)?)
+        } else if host.ends_with("worldcat.org") {
+            (Catalogue::WorldCat, next_data(dom)?)
+        } else if host == "openlibrary.org" {
+            (Catalogue::OpenLibrary, microdata(dom)?)
+        } else {
+            (Catalogue::Publisher, microdata(dom)?)
+        };
+
+        if fields.is_empty() {
+            return Ok(None);
+        }
+
+        let mut url = dom.url().clone();
+        url.set_fragment(None);
+
+        let mut book = Self {
+            catalogue,
+            title: None,
+            authors: Vec::new(),
+            editors: Vec::new(),
+            publisher: None,
+            place: None,
+            edition: None,
+            date: None,
+            series: None,
+            series_number: None,
+            volume_total: None,
+            pages: None,
+            isbns: Vec::new(),
+            url,
+        };
+        for (label, value) in fields {
+            book.push(&label, value);
+        }
+
+        Ok(Some(book))
+    }
+
+    /// Adds a labeled field like "Publisher: Penguin, 2003" of a catalogue or a microdata property.
+    fn push(&mut self, label: &str, value: String) {
+        let label = label.to_lowercase().replace([' ', ':', '-', '_'], "");
+        let value = value.trim().to_owned();
+        if value.is_empty() {
+            return;
+        }
+
+        match label.as_str() {
+            "title" | "name" => {
+                self.title.get_or_insert(value);
+            }
+            "author" | "authors" | "by" | "creator" => self.authors.extend(names(&value)),
+            "editor" | "editors" => self.editors.extend(names(&value)),
+            // e.g. "Penguin, 2003" of Google Books
+            "publisher" => match value.rsplit_once(", ") {
+                Some((publisher, year)) if let Ok(year) = year.parse() => {
+                    self.publisher.get_or_insert(publisher.to_owned());
+                    self.date.get_or_insert(PartialDate {
+                        year,
+                        month: None,
+                        day: None,
+                    });
+                }
+                _ => {
+                    self.publisher.get_or_insert(value);
+                }
+            },
+            "published" | "datepublished" | "publicationdate" | "date" => {
+                if let Some(date) = value
+                    .parse()
+                    .ok()
+                    .or_else(|| PartialDate::from_words(&value))
+                {
+                    self.date = Some(date);
+                }
+            }
+            "place" | "publicationplace" | "placeofpublication" | "locationcreated" => {
+                self.place.get_or_insert(value);
+            }
+            "edition" | "bookedition" => {
+                self.edition.get_or_insert(edition(&value));
+            }
+            "isbn" | "isbns" | "isbn10" | "isbn13" => {
+                for isbn in value
+                    .split([',', ';', ' '])
+                    .filter_map(|isbn| isbn.parse().ok())
+                {
+                    if !self.isbns.contains(&isbn) {
+                        self.isbns.push(isbn);
+                    }
+                }
+            }
+            "length" | "pages" | "numberofpages" | "physicaldescription" | "extent" => {
+                let (pages, volumes) = extent(&value);
+                self.pages = self.pages.or(pages);
+                self.volume_total = self.volume_total.or(volumes);
+            }
+            "series" | "ispartof" | "bookseries" => {
+                let (series, number) = series(&value);
+                self.series.get_or_insert(series);
+                self.series_number = self.series_number.or(number);
+            }
+            _ => (),
+        }
+    }
+}
+
+/// The "About this edition" table of Google Books.
+fn metadata_table(dom: &Dom) -> MetaResult<Vec<(String, String)>> {
+    let row_selector = Selector::parse("#metadata_content_table tr")?;
+    let label_selector = Selector::parse(".metadata_label")?;
+    let value_selector = Selector::parse(".metadata_value")?;
+
+    let fields = dom
+        .html()
+        .select(&row_selector)
+        .filter_map(|row| {
+            let label = row.select(&label_selector).next()?;
+            let value = row.select(&value_selector).next()?;
+            Some((text(label), text(value)))
+        })
+        .collect();
+
+    Ok(fields)
+}
+
+/// The properties of a schema.org `Book` described with microdata, e.g. by Open Library.
+fn microdata(dom: &Dom) -> MetaResult<Vec<(String, String)>> {
+    let book_selector = Selector::parse("[itemscope][itemtype*=\"schema.org/Book\"]")?;
+    let property_selector = Selector::parse("[itemprop]")?;
+    let name_selector = Selector::parse("[itemprop=\"name\"]")?;
+
+    let book = match dom.html().select(&book_selector).next() {
+        Some(book) => book,
+        None => return Ok(Vec::new()),
+    };
+
+    // properties of nested items like the author belong to the nested item
+    let scope = |el: &ElementRef| {
+        el.ancestors()
+            .filter_map(ElementRef::wrap)
+            .find(|ancestor| ancestor.value().attr("itemscope").is_some())
+            .map(|ancestor| ancestor.id())
+    };
+
+    let fields = book
+        .select(&property_selector)
+        .filter(|el| scope(el) == Some(book.id()))
+        .filter_map(|el| {
+            let property = el.value().attr("itemprop")?;
+            let value = match el.value().attr("content") {
+                Some(content) => content.to_owned(),
+                None if el.value().attr("itemscope").is_some() => el
+                    .select(&name_selector)
+                    .next()
+                    .map(text)
+                    .unwrap_or(text(el)),
+                None => text(el),
+            };
+            Some((property.to_owned(), value))
+        })
+        .collect();
+
+    Ok(fields)
+}
+
+/// The record of the Next.js data of WorldCat.
+fn next_data(dom: &Dom) -> MetaResult<Vec<(String, String)>> {
+    let json = match dom.text("script#__NEXT_DATA__")? {
+        Some(json) => json,
+        None => return Ok(Vec::new()),
+    };
+    let data = serde_json::from_str::<Value>(&json)?;
+
+    let record = match find_record(&data) {
+        Some(record) => record,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut fields = Vec::new();
+    for key in [
+        "title",
+        "publisher",
+        "publicationPlace",
+        "publicationDate",
+        "edition",
+        "physicalDescription",
+        "series",
+    ] {
+        if let Some(value) = record.get(key).and_then(Value::as_str) {
+            fields.push((key.to_owned(), value.to_owned()));
+        }
+    }
+
+    if let Some(isbns) = record.get("isbns").and_then(Value::as_array) {
+        let isbns = isbns.iter().filter_map(Value::as_str).collect::<Vec<_>>();
+        fields.push(("isbns".to_owned(), isbns.join(",")));
+    }
+
+    // e.g. {"firstName": {"text": "Jane"}, "secondName": {"text": "Doe"}}
+    let contributors = record.get("contributors").and_then(Value::as_array);
+    for contributor in contributors.into_iter().flatten() {
+        let part = |key: &str| contributor.get(key)?.get("text")?.as_str();
+        let name = [part("firstName"), part("secondName")]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+        fields.push(("author".to_owned(), name));
+    }
+
+    Ok(fields)
+}
+
+// the bibliographic record is the object carrying the isbns, wherever it is nested
+fn find_record(value: &Value) -> Option<&serde_json::Map<String, Value>> {
+    match value {
+        Value::Object(object) if object.contains_key("isbns") => Some(object),
+        Value::Object(object) => object.values().find_map(find_record),
+        Value::Array(values) => values.iter().find_map(find_record),
+        _ => None,
+    }
+}
+
+fn text(el: ElementRef) -> String {
+    el.text()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// e.g. "Jane Doe, John Smith", "Jane Doe and John Smith" or "Doe, Jane; Smith, John"
+fn names(value: &str) -> Vec<Name> {
+    value
+        .split(';')
+        .flat_map(|names| names.split(" and "))
+        .flat_map(|names| {
+            let parts = names.split(',').map(str::trim).collect::<Vec<_>>();
+            match parts.as_slice() {
+                // a single name in the form "Last, First", e.g. "Doe, Jane" or "Tolkien, J. R. R.",
+                // as a list of two names would have two words each
+                [surname, given_name] if !surname.contains(' ') || !given_name.contains(' ') => {
+                    vec![names.trim()]
+                }
+                _ => parts,
+            }
+        })
+        .filter(|name| !name.is_empty())
+        .map(|name| name.parse().unwrap_or_else(|_| Name::literal(name)))
+        .collect()
+}
+
+/// The number of an edition like "2nd edition", other statements like "Revised edition" are kept.
+fn edition(value: &str) -> String {
+    let regex = EDITION_REGEX
+        .get_or_init(|| Regex::new(r"(?i)^(\d+)(?:st|nd|rd|th)?\.?\s*(?:ed\b|edition|$)").unwrap());
+
+    match regex.captures(value) {
+        Some(captures) => captures[1].to_owned(),
+        None => value.to_owned(),
+    }
+}
+
+/// The pages and the volumes of a physical description like "xii, 352 pages" or "3 volumes".
+fn extent(value: &str) -> (Option<i64>, Option<i64>) {
+    if let Ok(pages) = value.parse() {
+        return (Some(pages), None);
+    }
+
+    let pages = PAGES_REGEX
+        .get_or_init(|| Regex::new(r"(?i)(\d+)\s*(?:pages|pp\b|p\b|seiten|s\.)").unwrap());
+    let volumes = VOLUMES_REGEX
+        .get_or_init(|| Regex::new(r"(?i)(\d+)\s*(?:volumes|vols?\b|v\.|bände|bd\.)").unwrap());
+    let number = |regex: &Regex| regex.captures(value)?[1].parse().ok();
+
+    (number(pages), number(volumes))
+}
+
+/// The name of a series and the number of the book in it, e.g. of "Lecture Notes in Physics ; 12".
+fn series(value: &str) -> (String, Option<i64>) {
+    let regex = SERIES_REGEX.get_or_init(|| {
+        Regex::new(
+            r"(?i)^(.*?)(?:\s*[;,#]\s*|\s+)(?:vol\.?|volume|no\.?|book|band|bd\.)?\s*(\d+)\s*$",
+        )
+        .unwrap()
+    });
+
+    match regex.captures(value) {
+        Some(captures) => (captures[1].trim().to_owned(), captures[2].parse().ok()),
+        None => (value.to_owned(), None),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{edition, extent, names, series, Catalogue, CatalogueBook};
+    use crate::dom::Dom;
+
+    #[test]
+    fn author_names() {
+        let surnames = |value: &str| {
+            names(value)
+                .into_iter()
+                .map(|name| name.surname)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(surnames("Doe, Jane"), ["Doe"]);
+        assert_eq!(surnames("Jane Doe, John Smith"), ["Doe", "Smith"]);
+        assert_eq!(surnames("Jane Doe and John Smith"), ["Doe", "Smith"]);
+        assert_eq!(surnames("Doe, Jane; Smith, John"), ["Doe", "Smith"]);
+        assert_eq!(surnames("Tolkien, J. R. R."), ["Tolkien"]);
+    }
+
+    #[test]
+    fn google_books_table() {
+        let html = r#"<html><body><table id="metadata_content_table">
+            <tr><td class="metadata_label">Title</td>
+                <td class="metadata_value"><span>The Rust Programming Language</span></td></tr>
+            <tr><td class="metadata_label">Authors</td>
+                <td class="metadata_value"><a>Steve Klabnik</a>, <a>Carol Nichols</a></td></tr>
+            <tr><td class="metadata_label">Edition</td>
+                <td class="metadata_value">2</td></tr>
+            <tr><td class="metadata_label">Publisher</td>
+                <td class="metadata_value">No Starch Press, 2023</td></tr>
+            <tr><td class="metadata_label">ISBN</td>
+                <td class="metadata_value">1718503105, 9781718503106</td></tr>
+            <tr><td class="metadata_label">Length</td>
+                <td class="metadata_value">560 pages</td></tr>
+        </table></body></html>"#;
+        let dom = Dom::parse(html, "https://books.google.com/books?id=abc123#v=onepage").unwrap();
+        let book = CatalogueBook::detect(&dom).unwrap().unwrap();
+
+        assert_eq!(book.catalogue, Catalogue::GoogleBooks);
+        assert_eq!(book.title.as_deref(), Some("The Rust Programming Language"));
+        assert_eq!(book.authors.len(), 2);
+        assert_eq!(book.authors[1].surname, "Nichols");
+        assert_eq!(book.edition.as_deref(), Some("2"));
+        assert_eq!(book.publisher.as_deref(), Some("No Starch Press"));
+        assert_eq!(book.date.map(|date| date.year), Some(2023));
+        assert_eq!(book.isbns.len(), 1);
+        assert_eq!(book.pages, Some(560));
+        assert_eq!(
+            book.url.as_str(),
+            "https://books.google.com/books?id=abc123"
+        );
+    }
+
+    #[test]
+    fn open_library_microdata() {
+        let html = r#"<html><body>
+            <div itemscope itemtype="https://schema.org/Book">
+                <h1 itemprop="name">The Hobbit</h1>
+                <div itemprop="author" itemscope itemtype="https://schema.org/Person">
+                    <a itemprop="name">Tolkien, J. R. R.</a>
+                </div>
+                <span itemprop="publisher">George Allen &amp; Unwin</span>
+                <span itemprop="datePublished">1937</span>
+                <span itemprop="numberOfPages">310</span>
+                <span itemprop="isbn">9780048231888</span>
+            </div>
+        </body></html>"#;
+        let dom = Dom::parse(html, "https://openlibrary.org/books/OL1M/The_Hobbit").unwrap();
+        let book = CatalogueBook::detect(&dom).unwrap().unwrap();
+
+        assert_eq!(book.catalogue, Catalogue::OpenLibrary);
+        assert_eq!(book.title.as_deref(), Some("The Hobbit"));
+        assert_eq!(book.authors.len(), 1);
+        assert_eq!(book.authors[0].surname, "Tolkien");
+        assert_eq!(book.publisher.as_deref(), Some("George Allen & Unwin"));
+        assert_eq!(book.date.map(|date| date.year), Some(1937));
+        assert_eq!(book.pages, Some(310));
+        assert_eq!(book.isbns.len(), 1);
+    }
+
+    #[test]
+    fn catalogue_fields() {
+        assert_eq!(edition("2nd edition"), "2");
+        assert_eq!(edition("Revised edition"), "Revised edition");
+        assert_eq!(extent("xii, 352 pages"), (Some(352), None));
+        assert_eq!(extent("3 volumes"), (None, Some(3)));
+        assert_eq!(
+            series("Lecture Notes in Physics ; 12"),
+            ("Lecture Notes in Physics".to_owned(), Some(12))
+        );
+        assert_eq!(
+            series("Penguin Classics"),
+            ("Penguin Classics".to_owned(), None)
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{dom::Dom, error::MetaResult, meta::value::Name};
+use regex::Regex;
+use scraper::Selector;
+use std::sync::OnceLock;
+use url::Url;
+
+// the first path segments of docs.rs which do not belong to a crate
+const DOCS_RS_RESERVED: &[&str] = &["-", "about", "releases", "search", "crate"];
+
+static VERSION_REGEX: OnceLock<Regex> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CratePage {
+    /// The page of the crate on crates.io or its overview on docs.rs.
+    Registry,
+    /// The api documentation of the crate on docs.rs.
+    Documentation,
+}
+
+/// A Rust crate on crates.io, e.g. "https://crates.io/crates/serde/1.0.197",
+/// or its documentation on docs.rs, e.g. "https://docs.rs/serde/1.0.197/serde/trait.Serialize.html".
+#[derive(Debug, PartialEq, Eq)]
+pub struct RustCrate {
+    pub page: CratePage,
+    pub name: String,
+    /// The exact version, even if the page was opened as the latest version.
+    pub version: Option<String>,
+    /// The users and teams owning the crate on crates.io.
+    pub owners: Vec<Name>,
+    /// The license expression, e.g. "MIT OR Apache-2.0".
+    pub license: Option<String>,
+    /// The source code repository of the crate.
+    pub repository: Option<Url>,
+    /// The documented item, e.g. "Serialize in serde".
+    pub item: Option<String>,
+    /// The url of the page pinned to the version, so the citation stays reproducible.
+    pub url: Url,
+}
+
+impl RustCrate {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let url = dom.url();
+        let segments = match url.path_segments() {
+            Some(segments) => segments
+                .filter(|segment| !segment.is_empty())
+                .collect::<Vec<_>>(),
+            None => return Ok(None),
+        };
+
+        let (page, name, version) = match (url.host_str(), segments.as_slice()) {
+            (Some("crates.io"), ["crates", name, rest @ ..]) => {
+                (CratePage::Registry, *name, rest.first())
+            }
+            (Some("docs.rs"), ["crate", name, rest @ ..]) => {
+                (CratePage::Registry, *name, rest.first())
+            }
+            (Some("docs.rs"), [name, rest @ ..]) if !DOCS_RS_RESERVED.contains(name) => {
+                (CratePage::Documentation, *name, rest.first())
+            }
+            _ => return Ok(None),
+        };
+
+        // e.g. "https://docs.rs/serde/latest/serde/" shows the version only on the page
+        let version = match version.filter(|version| is_version(version)) {
+            Some(version) => Some(version.to_string()),
+            None => dom
+                .text("h1 small, .crate-name")?
+                .and_then(|text| page_version(&text, name)),
+        };
+
+        let mut pinned = url.clone();
+        pinned.set_query(None);
+        pinned.set_fragment(None);
+        if let Some(version) = &version {
+            let path = match (url.host_str(), page) {
+                (Some("crates.io"), _) => format!("crates/{name}/{version}"),
+                (_, CratePage::Registry) => format!("crate/{name}/{version}"),
+                (_, CratePage::Documentation) => {
+                    let rest = segments.get(2..).unwrap_or_default().join("/");
+                    format!("{name}/{version}/{rest}")
+                }
+            };
+            pinned.set_path(&path);
+        }
+
+        // rustdoc titles its pages e.g. "Serialize in serde - Rust"
+        let item = match page {
+            CratePage::Documentation => dom
+                .text("title")?
+                .map(|title| title.trim_end_matches(" - Rust").to_owned()),
+            CratePage::Registry => None,
+        };
+
+        Ok(Some(Self {
+            page,
+            name: name.to_owned(),
+            version,
+            owners: owners(dom)?,
+            license: license(dom)?,
+            repository: repository(dom)?,
+            item,
+            url: pinned,
+        }))
+    }
+}
+
+/// Whether the text is a full semantic version like "1.0.197" or "0.4.0-beta.1+build.5",
+/// as partial versions like "1" of "https://docs.rs/serde/1/serde/" are resolved by docs.rs.
+fn is_version(text: &str) -> bool {
+    let regex = VERSION_REGEX.get_or_init(|| {
+        Regex::new(r"^\d+\.\d+\.\d+(?:-[0-9A-Za-z.-]+)?(?:\+[0-9A-Za-z.-]+)?$").unwrap()
+    });
+
+    regex.is_match(text)
+}
+
+/// The version shown on the page, e.g. "v1.0.197" on crates.io or "serde-1.0.197" on docs.rs.
+fn page_version(text: &str, name: &str) -> Option<String> {
+    let version = text
+        .split_whitespace()
+        .map(|word| {
+            word.strip_prefix(name)
+                .map_or(word, |word| word.trim_start_matches('-'))
+        })
+        .map(|word| word.trim_start_matches('v'))
+        .find(|word| is_version(word))?;
+
+    Some(version.to_owned())
+}
+
+fn owners(dom: &Dom) -> MetaResult<Vec<Name>> {
+    let selector = Selector::parse(
+        "a[href^=\"/users/\"], a[href^=\"/teams/\"], a[href^=\"https://crates.io/users/\"], \
+         a[href^=\"https://crates.io/teams/\"]",
+    )?;
+
+    let mut owners = Vec::new();
+    for el in dom.html().select(&selector) {
+        // the login of the owner, e.g. "dtolnay" or "github:serde-rs:publish"
+        let login = el
+            .value()
+            .attr("href")
+            .and_then(|href| href.trim_end_matches('/').rsplit('/').next());
+
+        if let Some(login) = login
+            && !owners.iter().any(|owner: &Name| owner.surname == login)
+        {
+            owners.push(Name::literal(login));
+        }
+    }
+
+    Ok(owners)
+}
+
+fn license(dom: &Dom) -> MetaResult<Option<String>> {
+    if let Some(license) = dom.text("[data-test-license]")? {
+        return Ok(Some(license));
+    }
+
+    // docs.rs links each license of the expression to its SPDX page
+    let selector = Selector::parse("a[href^=\"https://spdx.org/licenses/\"]")?;
+    let licenses = dom
+        .html()
+        .select(&selector)
+        .map(|el| el.text().collect::<String>().trim().to_owned())
+        .filter(|license| !license.is_empty())
+        .collect::<Vec<_>>();
+
+    if !licenses.is_empty() {
+        Ok(Some(licenses.join(" OR ")))
+    } else {
+        Ok(None)
+    }
+}
+
+fn repository(dom: &Dom) -> MetaResult<Option<Url>> {
+    if let Some(href) = dom.attr("[data-test-repository-link] a", "href")? {
+        return Ok(href.parse().ok());
+    }
+
+    // the "Repository" link in the crate menu of docs.rs
+    let selector = Selector::parse("a[href^=\"http\"]")?;
+    let repository = dom
+        .html()
+        .select(&selector)
+        .find(|el| el.text().collect::<String>().trim() == "Repository")
+        .and_then(|el| el.value().attr("href"))
+        .and_then(|href| href.parse().ok());
+
+    Ok(repository)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_version, page_version, RustCrate};
+    use crate::dom::Dom;
+
+    #[test]
+    fn full_version() {
+        assert!(is_version("1.0.197"));
+        assert!(is_version("0.4.0-beta.1+build.5"));
+        assert!(!is_version("1"));
+        assert!(!is_version("1.0"));
+        assert!(!is_version("2021-edition"));
+        assert!(!is_version("latest"));
+    }
+
+    #[test]
+    fn partial_version_from_page() {
+        let html = r#"<html><body><span class="crate-name">serde-1.0.197</span></body></html>"#;
+        let dom = Dom::parse(html, "https://docs.rs/serde/1/serde/").unwrap();
+        let rust_crate = RustCrate::detect(&dom).unwrap().unwrap();
+
+        assert_eq!(rust_crate.version.as_deref(), Some("1.0.197"));
+        assert_eq!(
+            rust_crate.url.as_str(),
+            "https://docs.rs/serde/1.0.197/serde"
+        );
+    }
+
+    #[test]
+    fn version_from_page() {
+        assert_eq!(
+            page_version("serde-1.0.197", "serde").as_deref(),
+            Some("1.0.197")
+        );
+        assert_eq!(
+            page_version("serde v1.0.197", "serde").as_deref(),
+            Some("1.0.197")
+        );
+        assert_eq!(page_version("serde", "serde"), None);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{
+    dom::Dom,
+    error::MetaResult,
+    meta::value::{Doi, Name, PartialDate},
+};
+use scraper::Selector;
+
+/// A record of the Zenodo research repository, e.g. "https://zenodo.org/records/1234".
+/// Zenodo embeds the rest of its metadata as schema.org JSON-LD.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ZenodoRecord {
+    pub id: String,
+}
+
+impl ZenodoRecord {
+    pub fn detect(dom: &Dom) -> Option<Self> {
+        let url = dom.url();
+
+        if !matches!(url.host_str()?, "zenodo.org" | "sandbox.zenodo.org") {
+            return None;
+        }
+
+        let mut segments = url.path_segments()?;
+        match (segments.next(), segments.next()) {
+            (Some("records" | "record"), Some(id)) if id.chars().all(|c| c.is_ascii_digit()) => {
+                Some(Self { id: id.to_owned() })
+            }
+            _ => None,
+        }
+    }
+
+    /// The doi Zenodo registers for each of its records.
+    pub fn doi(&self) -> String {
+        format!("10.5281/zenodo.{}", self.id)
+    }
+}
+
+/// A dataset page of a CKAN data portal, e.g. "https://catalog.data.gov/dataset/some-name".
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CkanDataset {
+    pub title: Option<String>,
+    pub authors: Vec<Name>,
+    /// The organization that published the dataset on the portal.
+    pub organization: Option<String>,
+    /// The name of the portal, e.g. "Data.gov".
+    pub portal: Option<String>,
+    pub created: Option<PartialDate>,
+    pub version: Option<String>,
+    pub license: Option<String>,
+    /// The doi registered for the dataset, e.g. by the ckanext-doi extension.
+    pub doi: Option<Doi>,
+}
+
+impl CkanDataset {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let is_ckan = dom
+            .meta_content("meta[name=\"generator\"]")?
+            .is_some_and(|generator| generator.to_lowercase().starts_with("ckan"));
+        let is_dataset = dom.url().path().contains("/dataset/");
+
+        if !is_ckan || !is_dataset {
+            return Ok(None);
+        }
+
+        // the heading of the dataset comes before the heading of the page
+        let title = match dom.text(".module-content h1")? {
+            Some(title) => Some(title),
+            None => dom.text("h1")?,
+        };
+
+        let mut dataset = Self {
+            title,
+            organization: dom.text(".breadcrumb a[href*=\"/organization/\"]")?,
+            portal: dom.meta_content("meta[property=\"og:site_name\"]")?,
+            license: dom.text("a[rel=\"dc:rights\"], .license a")?,
+            ..Default::default()
+        };
+
+        // the "Additional Info" table below the resources of a dataset
+        let row_selector = Selector::parse(".additional-info tr")?;
+        let th_selector = Selector::parse("th")?;
+        let td_selector = Selector::parse("td")?;
+
+        for row in dom.html().select(&row_selector) {
+            let key = row.select(&th_selector).next();
+            let value = row.select(&td_selector).next();
+
+            let (key, value) = match (key, value) {
+                (Some(key), Some(value)) => (
+                    key.text().collect::<String>(),
+                    value.text().collect::<String>(),
+                ),
+                _ => continue,
+            };
+            let value = value.trim();
+
+            match key.trim() {
+                "Author" => dataset.authors.extend(value.parse::<Name>().ok()),
+                "Version" => dataset.version = Some(value.to_owned()),
+                "Created" => dataset.created = value.parse().ok(),
+                "DOI" | "Identifier" if dataset.doi.is_none() => dataset.doi = value.parse().ok(),
+                _ => (),
+            }
+        }
+
+        if dataset.doi.is_none() {
+            dataset.doi = dom
+                .attr("a[href*=\"doi.org/10.\"]", "href")?
+                .and_then(|href| href.parse().ok());
+        }
+
+        Ok(Some(dataset))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CkanDataset, ZenodoRecord};
+    use crate::dom::Dom;
+
+    #[test]
+    fn zenodo_record_doi() {
+        let dom = Dom::parse("<html></html>", "https://zenodo.org/records/1234").unwrap();
+        let record = ZenodoRecord::detect(&dom).unwrap();
+        assert_eq!(record.doi(), "10.5281/zenodo.1234");
+
+        let dom = Dom::parse("<html></html>", "https://zenodo.org/communities/abc").unwrap();
+        assert_eq!(ZenodoRecord::detect(&dom), None);
+    }
+
+    #[test]
+    fn ckan_dataset() {
+        let html = r#"<html><head><meta name="generator" content="ckan 2.10.1"></head><body>
+            <h1>Data.gov</h1>
+            <div class="module-content"><h1>Air Quality</h1></div>
+            <table class="additional-info">
+                <tr><th>Author</th><td>Jane Doe</td></tr>
+                <tr><th>Version</th><td>2.1</td></tr>
+                <tr><th>DOI</th><td>https://doi.org/10.1234/air.5678</td></tr>
+            </table>
+        </body></html>"#;
+        let dom = Dom::parse(html, "https://catalog.data.gov/dataset/air-quality").unwrap();
+        let dataset = CkanDataset::detect(&dom).unwrap().unwrap();
+
+        assert_eq!(dataset.title.as_deref(), Some("Air Quality"));
+        assert_eq!(dataset.authors.len(), 1);
+        assert_eq!(dataset.version.as_deref(), Some("2.1"));
+        assert_eq!(
+            dataset.doi.map(String::from).as_deref(),
+            Some("10.1234/air.5678")
+        );
+
+        let dom = Dom::parse(html, "https://catalog.data.gov/organization/epa").unwrap();
+        assert_eq!(CkanDataset::detect(&dom).unwrap(), None);
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{dom::Dom, error::MetaResult};
+
+/// The static site generators documentation is commonly written with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocsGenerator {
+    /// e.g. "The Rust Programming Language" or the Rustonomicon.
+    MdBook,
+    Sphinx,
+    MkDocs,
+}
+
+impl DocsGenerator {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let generator = dom
+            .meta_content("meta[name=\"generator\"]")?
+            .unwrap_or_default()
+            .to_lowercase();
+
+        // mdBook has no generator tag, but a sidebar with the table of contents
+        let generator = if generator.starts_with("mkdocs") {
+            Self::MkDocs
+        } else if generator.starts_with("docutils")
+            || generator.starts_with("sphinx")
+            || dom.text(".sphinxsidebar, .wy-nav-side")?.is_some()
+        {
+            Self::Sphinx
+        } else if dom.text("h1.menu-title")?.is_some() && dom.text("#sidebar .chapter")?.is_some() {
+            Self::MdBook
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(generator))
+    }
+}
+
+/// A page of a book or manual generated by one of the [DocsGenerator]s.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DocsChapter {
+    pub generator: DocsGenerator,
+    /// The title of the whole book, e.g. "The Rust Programming Language".
+    pub book: Option<String>,
+    pub title: Option<String>,
+    /// The number of the chapter as shown in the navigation, e.g. "3.2".
+    pub number: Option<String>,
+}
+
+impl DocsChapter {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let generator = match DocsGenerator::detect(dom)? {
+            Some(generator) => generator,
+            None => return Ok(None),
+        };
+
+        // the title of a page is e.g. "Data Types - The Rust Programming Language"
+        // or "Installation — Project 1.0 documentation"
+        let page_title = dom.text("title")?;
+        let (title_chapter, title_book) = match page_title
+            .as_deref()
+            .and_then(|title| title.rsplit_once(" — ").or(title.rsplit_once(" - ")))
+        {
+            Some((chapter, book)) => (
+                Some(chapter.trim().to_owned()),
+                Some(book.trim_end_matches(" documentation").trim().to_owned()),
+            ),
+            None => (page_title, None),
+        };
+
+        let book = match generator {
+            DocsGenerator::MdBook => dom.text("h1.menu-title")?,
+            DocsGenerator::Sphinx => dom.text(
+                ".wy-side-nav-search > a, .sidebar-brand-text, .sphinxsidebarwrapper h1 a, \
+                 .navbar-brand",
+            )?,
+            DocsGenerator::MkDocs => dom.text(".md-header__topic .md-ellipsis, .navbar-brand")?,
+        };
+
+        let number = match generator {
+            DocsGenerator::MdBook => dom.text("#sidebar a.active strong")?,
+            DocsGenerator::Sphinx => dom.text("h1 .section-number")?,
+            DocsGenerator::MkDocs => None,
+        }
+        .map(|number| number.trim_end_matches('.').to_owned());
+
+        let title = dom
+            .text("main h1, article h1, [role=\"main\"] h1, .body h1")?
+            .map(|heading| clean_heading(&heading, number.as_deref()))
+            .or(title_chapter);
+
+        Ok(Some(Self {
+            generator,
+            book: book.or(title_book),
+            title,
+            number,
+        }))
+    }
+}
+
+/// Removes the permalink sign and the chapter number of a heading, e.g. "3.2. Data Types¶".
+fn clean_heading(heading: &str, number: Option<&str>) -> String {
+    let heading = heading.trim().trim_end_matches(['¶', '#']).trim_end();
+
+    match number.and_then(|number| heading.strip_prefix(number)) {
+        Some(heading) => heading.trim_start_matches('.').trim_start().to_owned(),
+        None => heading.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{clean_heading, DocsChapter, DocsGenerator};
+    use crate::dom::Dom;
+
+    #[test]
+    fn heading_without_number() {
+        assert_eq!(clean_heading("3.2. Data Types¶", Some("3.2")), "Data Types");
+        assert_eq!(clean_heading("Data Types", None), "Data Types");
+    }
+
+    #[test]
+    fn mdbook_chapter() {
+        let html = r##"<html><head>
+            <title>Data Types - The Rust Programming Language</title>
+        </head><body>
+            <nav id="sidebar"><ol class="chapter"><li class="chapter-item">
+                <a href="ch03-02-data-types.html" class="active"><strong>3.2.</strong> Data Types</a>
+            </li></ol></nav>
+            <div id="menu-bar"><h1 class="menu-title">The Rust Programming Language</h1></div>
+            <main><h1 id="data-types"><a class="header" href="#data-types">Data Types</a></h1></main>
+        </body></html>"##;
+        let dom = Dom::parse(
+            html,
+            "https://doc.rust-lang.org/book/ch03-02-data-types.html",
+        )
+        .unwrap();
+        let chapter = DocsChapter::detect(&dom).unwrap().unwrap();
+
+        assert_eq!(chapter.generator, DocsGenerator::MdBook);
+        assert_eq!(
+            chapter.book.as_deref(),
+            Some("The Rust Programming Language")
+        );
+        assert_eq!(chapter.title.as_deref(), Some("Data Types"));
+        assert_eq!(chapter.number.as_deref(), Some("3.2"));
+    }
+
+    #[test]
+    fn sphinx_chapter() {
+        let html = r##"<html><head>
+            <meta name="generator" content="Docutils 0.19: https://docutils.sourceforge.io/">
+            <title>3.2. Installation — Example 1.0 documentation</title>
+        </head><body>
+            <div class="body" role="main">
+                <h1><span class="section-number">3.2. </span>Installation<a class="headerlink"
+                    href="#installation">¶</a></h1>
+            </div>
+            <div class="sphinxsidebar"><div class="sphinxsidebarwrapper">
+                <h1 class="logo"><a href="index.html">Example</a></h1>
+            </div></div>
+        </body></html>"##;
+        let dom = Dom::parse(
+            html,
+            "https://example.readthedocs.io/en/latest/install.html",
+        )
+        .unwrap();
+        let chapter = DocsChapter::detect(&dom).unwrap().unwrap();
+
+        assert_eq!(chapter.generator, DocsGenerator::Sphinx);
+        assert_eq!(chapter.book.as_deref(), Some("Example"));
+        assert_eq!(chapter.title.as_deref(), Some("Installation"));
+        assert_eq!(chapter.number.as_deref(), Some("3.2"));
+
+        let dom = Dom::parse("<html><h1>Home</h1></html>", "https://example.org/").unwrap();
+        assert_eq!(DocsChapter::detect(&dom).unwrap(), None);
+    }
+}
@@ -0,0 +1,386 @@
+use crate::{
+    dom::Dom,
+    error::MetaResult,
+    meta::value::{Name, PartialDate},
+};
+use scraper::{ElementRef, Selector};
+use url::Url;
+
+// the hosts of the Stack Exchange network besides "*.stackexchange.com"
+const STACK_EXCHANGE_HOSTS: &[&str] = &[
+    "stackoverflow.com",
+    "superuser.com",
+    "serverfault.com",
+    "askubuntu.com",
+    "mathoverflow.net",
+    "stackapps.com",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Forum {
+    /// Stack Overflow or another site of the Stack Exchange network.
+    StackExchange,
+    Discourse,
+    PhpBb,
+}
+
+impl Forum {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let url = dom.url();
+        let host = url.host_str().unwrap_or_default();
+
+        let forum = if STACK_EXCHANGE_HOSTS.contains(&host) || host.ends_with(".stackexchange.com")
+        {
+            Self::StackExchange
+        } else if dom
+            .meta_content("meta[name=\"generator\"]")?
+            .is_some_and(|generator| generator.starts_with("Discourse"))
+        {
+            Self::Discourse
+        } else if url.path().ends_with("viewtopic.php") {
+            Self::PhpBb
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(forum))
+    }
+
+    fn selectors(&self) -> ForumSelectors {
+        match self {
+            Self::StackExchange => ForumSelectors {
+                posts: "#question, .answer",
+                question: Some("#question"),
+                title: "#question-header h1, h1[itemprop=\"name\"]",
+                author: "[itemprop=\"author\"] [itemprop=\"name\"], [itemprop=\"author\"] a",
+                date: "[itemprop=\"dateCreated\"], .user-action-time .relativetime",
+            },
+            // the crawler view is described with microdata, the web app without
+            Self::Discourse => ForumSelectors {
+                posts: "[id^=\"post_\"]",
+                question: Some("#post_1, [data-post-number=\"1\"]"),
+                title: "#topic-title h1, h1",
+                author: "[itemprop=\"author\"] [itemprop=\"name\"], .names .first a",
+                date: "[itemprop=\"datePublished\"], .post-date time",
+            },
+            Self::PhpBb => ForumSelectors {
+                posts: ".post[id^=\"p\"]",
+                question: None,
+                title: "h2.topic-title, h2",
+                author: ".author .username, .author .username-coloured",
+                date: ".author time",
+            },
+        }
+    }
+}
+
+struct ForumSelectors {
+    posts: &'static str,
+    /// The question or the first post of the topic, which is otherwise
+    /// the first post of the first page.
+    question: Option<&'static str>,
+    title: &'static str,
+    author: &'static str,
+    date: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForumPost {
+    pub author: Option<Name>,
+    pub published: Option<PartialDate>,
+    /// The permanent link of the post.
+    pub url: Url,
+}
+
+/// A question or topic of a forum, together with the answer or reply the url points to.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ForumThread {
+    pub forum: Forum,
+    /// The name of the site, e.g. "Stack Overflow".
+    pub site_name: Option<String>,
+    pub title: Option<String>,
+    /// The question or the first post of the topic.
+    pub question: ForumPost,
+    pub answer: Option<ForumPost>,
+}
+
+impl ForumThread {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let forum = match Forum::detect(dom)? {
+            Some(forum) => forum,
+            None => return Ok(None),
+        };
+        let (question_url, answer) = match permalinks(dom.url(), forum) {
+            Some(permalinks) => permalinks,
+            None => return Ok(None),
+        };
+
+        let selectors = forum.selectors();
+        let post_selector = Selector::parse(selectors.posts)?;
+        let author_selector = Selector::parse(selectors.author)?;
+        let date_selector = Selector::parse(selectors.date)?;
+
+        let post = |el: Option<ElementRef>, url: Url| {
+            let author = el
+                .and_then(|el| el.select(&author_selector).next())
+                .map(|author| author.text().collect::<String>().trim().to_owned())
+                .filter(|author| !author.is_empty())
+                .map(Name::literal);
+            let published = el
+                .and_then(|el| el.select(&date_selector).next())
+                .and_then(|date| {
+                    let date = date.value();
+                    date.attr("datetime")
+                        .or(date.attr("content"))
+                        .or(date.attr("title"))
+                })
+                .and_then(|date| date.parse().ok());
+
+            ForumPost {
+                author,
+                published,
+                url,
+            }
+        };
+
+        let posts = dom.html().select(&post_selector).collect::<Vec<_>>();
+        let question = match selectors.question {
+            Some(question) => dom.html().select(&Selector::parse(question)?).next(),
+            None if is_first_page(dom.url()) => posts.first().copied(),
+            None => None,
+        };
+        let question = post(question, question_url);
+        let answer = answer.map(|(id, url)| {
+            let el = posts
+                .iter()
+                .find(|el| el.value().attr("id") == Some(&id))
+                .copied();
+            post(el, url)
+        });
+
+        let title = dom
+            .text(selectors.title)?
+            .or(dom.meta_content("meta[property=\"og:title\"]")?);
+
+        Ok(Some(Self {
+            forum,
+            site_name: dom.meta_content("meta[property=\"og:site_name\"]")?,
+            title,
+            question,
+            answer,
+        }))
+    }
+}
+
+/// Whether the page shows the first posts of the topic, e.g. not "viewtopic.php?t=123&start=25".
+fn is_first_page(url: &Url) -> bool {
+    url.query_pairs()
+        .find(|(key, _)| key == "start")
+        .is_none_or(|(_, start)| start == "0")
+}
+
+/// The permalink of the question, and the element id and the permalink of the answer.
+fn permalinks(url: &Url, forum: Forum) -> Option<(Url, Option<(String, Url)>)> {
+    let segments = url
+        .path_segments()?
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+    let is_number = |text: &&str| text.chars().all(|c| c.is_ascii_digit());
+
+    match forum {
+        // e.g. "/questions/11227809/slug/11227902#11227902" or "/a/11227902"
+        Forum::StackExchange => {
+            let fragment = url
+                .fragment()
+                .map(|fragment| fragment.trim_start_matches("answer-"))
+                .filter(|fragment| !fragment.is_empty() && is_number(fragment));
+
+            let (question, answer) = match segments.as_slice() {
+                ["questions" | "q", question, rest @ ..] => (
+                    Some(*question),
+                    rest.get(1).copied().filter(is_number).or(fragment),
+                ),
+                ["a", answer, ..] => (None, Some(*answer)),
+                _ => return None,
+            };
+
+            let question_url = match question {
+                Some(question) => url.join(&format!("/q/{question}")).ok()?,
+                None => url.clone(),
+            };
+            let answer = answer.and_then(|answer| {
+                let answer_url = url.join(&format!("/a/{answer}")).ok()?;
+                Some((format!("answer-{answer}"), answer_url))
+            });
+
+            Some((question_url, answer))
+        }
+        // e.g. "/t/slug/1234/5", where the first post is the topic itself
+        Forum::Discourse => match segments.as_slice() {
+            ["t", slug, topic, rest @ ..] => {
+                let question_url = url.join(&format!("/t/{slug}/{topic}")).ok()?;
+                let answer = rest
+                    .first()
+                    .copied()
+                    .filter(is_number)
+                    .filter(|post| *post != "1")
+                    .and_then(|post| {
+                        let answer_url = url.join(&format!("/t/{slug}/{topic}/{post}")).ok()?;
+                        Some((format!("post_{post}"), answer_url))
+                    });
+
+                Some((question_url, answer))
+            }
+            _ => None,
+        },
+        // e.g. "viewtopic.php?t=123" or "viewtopic.php?p=456#p456"
+        Forum::PhpBb => {
+            let param = |name: &str| {
+                url.query_pairs()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| value.into_owned())
+            };
+
+            let mut question_url = url.clone();
+            question_url.set_fragment(None);
+            if let Some(topic) = param("t") {
+                question_url
+                    .query_pairs_mut()
+                    .clear()
+                    .append_pair("t", &topic);
+            }
+
+            let answer = param("p").map(|post| {
+                let mut answer_url = url.clone();
+                answer_url.query_pairs_mut().clear().append_pair("p", &post);
+                answer_url.set_fragment(Some(&format!("p{post}")));
+                (format!("p{post}"), answer_url)
+            });
+
+            Some((question_url, answer))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{permalinks, Forum, ForumThread};
+    use crate::dom::Dom;
+
+    const PHPBB_HTML: &str = r#"<html><body>
+        <h2 class="topic-title">Compiling on FreeBSD</h2>
+        <div id="p101" class="post"><div class="author">
+            <a class="username">alice</a><time datetime="2024-01-02T10:00:00+00:00">Jan 2</time>
+        </div></div>
+        <div id="p102" class="post"><div class="author">
+            <a class="username">bob</a><time datetime="2024-01-03T10:00:00+00:00">Jan 3</time>
+        </div></div>
+    </body></html>"#;
+
+    #[test]
+    fn stack_exchange_permalinks() {
+        let url = "https://stackoverflow.com/questions/11227809/why-is-it-faster/11227902#11227902"
+            .parse()
+            .unwrap();
+        let (question, answer) = permalinks(&url, Forum::StackExchange).unwrap();
+        let (id, answer) = answer.unwrap();
+
+        assert_eq!(question.as_str(), "https://stackoverflow.com/q/11227809");
+        assert_eq!(id, "answer-11227902");
+        assert_eq!(answer.as_str(), "https://stackoverflow.com/a/11227902");
+    }
+
+    #[test]
+    fn discourse_question() {
+        let html = r#"<html><head><meta name="generator" content="Discourse 3.2.0">
+        </head><body>
+            <div id="topic-title"><h1>Async closures are stable</h1></div>
+            <div id="post_1" itemprop="mainEntity">
+                <span itemprop="author"><span itemprop="name">alice</span></span>
+                <time itemprop="datePublished" datetime="2024-01-02T10:00:00Z"></time>
+            </div>
+            <div id="post_5" itemprop="comment">
+                <span itemprop="author"><span itemprop="name">bob</span></span>
+                <time itemprop="datePublished" datetime="2024-01-05T10:00:00Z"></time>
+            </div>
+        </body></html>"#;
+        let url = "https://users.rust-lang.org/t/async-closures-are-stable/1234/5";
+        let thread = ForumThread::detect(&Dom::parse(html, url).unwrap())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(thread.forum, Forum::Discourse);
+        assert_eq!(thread.title.as_deref(), Some("Async closures are stable"));
+        assert_eq!(
+            thread.question.author.map(|author| author.surname),
+            Some("alice".to_owned())
+        );
+        assert_eq!(
+            thread.question.url.as_str(),
+            "https://users.rust-lang.org/t/async-closures-are-stable/1234"
+        );
+
+        let answer = thread.answer.unwrap();
+        assert_eq!(
+            answer.author.map(|author| author.surname),
+            Some("bob".to_owned())
+        );
+    }
+
+    #[test]
+    fn discourse_page_without_question() {
+        // a later page of the crawler view, which does not contain the first post
+        let html = r#"<html><head><meta name="generator" content="Discourse 3.2.0"></head>
+        <body>
+            <div id="post_21"><span itemprop="author"><span itemprop="name">carol</span></span></div>
+        </body></html>"#;
+        let url = "https://users.rust-lang.org/t/async-closures-are-stable/1234?page=2";
+        let thread = ForumThread::detect(&Dom::parse(html, url).unwrap())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(thread.question.author, None);
+    }
+
+    #[test]
+    fn phpbb_question() {
+        let url = "https://forum.example.org/viewtopic.php?t=123&start=0";
+        let thread = ForumThread::detect(&Dom::parse(PHPBB_HTML, url).unwrap())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(thread.forum, Forum::PhpBb);
+        assert_eq!(thread.title.as_deref(), Some("Compiling on FreeBSD"));
+        assert_eq!(
+            thread.question.author.map(|author| author.surname),
+            Some("alice".to_owned())
+        );
+        assert!(thread.question.published.is_some());
+        assert_eq!(
+            thread.question.url.as_str(),
+            "https://forum.example.org/viewtopic.php?t=123"
+        );
+    }
+
+    #[test]
+    fn phpbb_later_page() {
+        let url = "https://forum.example.org/viewtopic.php?t=123&start=25&p=102#p102";
+        let thread = ForumThread::detect(&Dom::parse(PHPBB_HTML, url).unwrap())
+            .unwrap()
+            .unwrap();
+
+        // the first post of a later page is a reply rather than the topic
+        assert_eq!(thread.question.author, None);
+        assert_eq!(thread.question.published, None);
+
+        let answer = thread.answer.unwrap();
+        assert_eq!(
+            answer.author.map(|author| author.surname),
+            Some("bob".to_owned())
+        );
+        assert_eq!(
+            answer.url.as_str(),
+            "https://forum.example.org/viewtopic.php?p=102#p102"
+        );
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{
+    dom::Dom,
+    error::MetaResult,
+    meta::value::{Name, PartialDate},
+    site::repository::Forge,
+};
+use url::Url;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueKind {
+    Issue,
+    /// A pull request, or a merge request of GitLab.
+    PullRequest,
+    /// The text of an accepted RFC in a repository like "rust-lang/rfcs",
+    /// numbered after the pull request which proposed it.
+    Rfc,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueState {
+    Open,
+    Closed,
+    Merged,
+}
+
+impl IssueState {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Open => "Open",
+            Self::Closed => "Closed",
+            Self::Merged => "Merged",
+        }
+    }
+
+    // e.g. "Open", "Draft", "Closed as completed" or "Merged"
+    fn parse(text: &str) -> Option<Self> {
+        let text = text.to_lowercase();
+
+        if text.contains("merged") {
+            Some(Self::Merged)
+        } else if text.contains("closed") {
+            Some(Self::Closed)
+        } else if text.contains("open") || text.contains("draft") {
+            Some(Self::Open)
+        } else {
+            None
+        }
+    }
+}
+
+struct IssueSelectors {
+    title: &'static str,
+    author: &'static str,
+    date: &'static str,
+    state: &'static str,
+}
+
+/// An issue, a pull request or an RFC of a repository hosted on a forge,
+/// e.g. "https://github.com/rust-lang/rust/issues/12345".
+#[derive(Debug, PartialEq, Eq)]
+pub struct Issue {
+    pub forge: Forge,
+    pub kind: IssueKind,
+    pub owner: String,
+    pub name: String,
+    pub number: String,
+    pub title: Option<String>,
+    /// The user who opened the issue.
+    pub author: Option<Name>,
+    pub opened: Option<PartialDate>,
+    pub state: Option<IssueState>,
+    /// The page of the issue without the path of e.g. its changed files.
+    pub url: Url,
+    pub repository_url: Url,
+}
+
+impl Issue {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        if let Some(rfc) = Self::detect_rfc_book(dom)? {
+            return Ok(Some(rfc));
+        }
+
+        let forge = match Forge::detect(dom)? {
+            Some(forge) => forge,
+            None => return Ok(None),
+        };
+        let (owner, name, kind, number, title) = match issue_path(dom, &forge)? {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+
+        let mut repository_url = dom.url().clone();
+        repository_url.set_query(None);
+        repository_url.set_fragment(None);
+        repository_url.set_path(&format!("/{owner}/{name}"));
+
+        let mut url = repository_url.clone();
+        match (&forge, kind) {
+            // the text of an RFC is pinned to the branch or commit of the url
+            (_, IssueKind::Rfc) => url.set_path(dom.url().path()),
+            (Forge::GitLab, IssueKind::PullRequest) => {
+                url.set_path(&format!("/{owner}/{name}/-/merge_requests/{number}"))
+            }
+            (Forge::GitLab, _) => url.set_path(&format!("/{owner}/{name}/-/issues/{number}")),
+            (Forge::GitHub, IssueKind::PullRequest) => {
+                url.set_path(&format!("/{owner}/{name}/pull/{number}"))
+            }
+            (_, IssueKind::PullRequest) => url.set_path(&format!("/{owner}/{name}/pulls/{number}")),
+            (_, IssueKind::Issue) => url.set_path(&format!("/{owner}/{name}/issues/{number}")),
+        }
+
+        let selectors = selectors(&forge);
+        let title = match title {
+            Some(title) => Some(title),
+            None => dom.text(selectors.title)?.or(dom
+                .meta_content("meta[property=\"og:title\"]")?
+                .and_then(|title| title.split(" · ").next().map(ToOwned::to_owned))),
+        };
+        let author = dom
+            .text(selectors.author)?
+            .map(|author| Name::literal(author.trim_start_matches('@')));
+        let opened = dom
+            .attr(selectors.date, "datetime")?
+            .and_then(|date| date.parse().ok());
+        let state = dom
+            .text(selectors.state)?
+            .and_then(|state| IssueState::parse(&state));
+
+        Ok(Some(Self {
+            forge,
+            kind,
+            owner,
+            name,
+            number,
+            title,
+            author,
+            opened,
+            state,
+            url,
+            repository_url,
+        }))
+    }
+
+    /// The RFCs rendered as book, e.g. "https://rust-lang.github.io/rfcs/2094-nll.html".
+    fn detect_rfc_book(dom: &Dom) -> MetaResult<Option<Self>> {
+        let url = dom.url();
+        let owner = match url
+            .host_str()
+            .and_then(|host| host.strip_suffix(".github.io"))
+        {
+            Some(owner) => owner,
+            None => return Ok(None),
+        };
+        let (number, title) = match url.path().strip_prefix("/rfcs/").and_then(rfc) {
+            Some(rfc) => rfc,
+            None => return Ok(None),
+        };
+
+        let mut page = url.clone();
+        page.set_query(None);
+        page.set_fragment(None);
+
+        Ok(Some(Self {
+            forge: Forge::GitHub,
+            kind: IssueKind::Rfc,
+            owner: owner.to_owned(),
+            name: "rfcs".to_owned(),
+            number,
+            title: Some(title),
+            author: None,
+            opened: None,
+            state: None,
+            url: page,
+            repository_url: Url::parse(&format!("https://github.com/{owner}/rfcs"))?,
+        }))
+    }
+
+    /// The reference of the issue within its forge, e.g. "rust-lang/rust#12345"
+    /// or "group/project!42" for a merge request of GitLab.
+    pub fn reference(&self) -> String {
+        let separator = match (&self.forge, self.kind) {
+            (Forge::GitLab, IssueKind::PullRequest) => '!',
+            _ => '#',
+        };
+
+        format!("{}/{}{separator}{}", self.owner, self.name, self.number)
+    }
+}
+
+fn selectors(forge: &Forge) -> IssueSelectors {
+    match forge {
+        // the markup of the React app and of the classic pages
+        Forge::GitHub => IssueSelectors {
+            title: "[data-testid=\"issue-title\"], .js-issue-title, bdi.markdown-title",
+            author: "[data-testid=\"issue-body-header-author\"], .gh-header-meta a.author, \
+                     .timeline-comment-header a.author",
+            date: "[data-testid=\"issue-body-header\"] relative-time, \
+                   .gh-header-meta relative-time, relative-time",
+            state: "[data-testid=\"header-state\"], .gh-header-meta .State, .State",
+        },
+        Forge::GitLab => IssueSelectors {
+            title: "[data-testid=\"issue-title\"], [data-testid=\"title-content\"], h1.title",
+            author: "[data-testid=\"author-name\"], .issuable-meta .author-link .author",
+            date: ".issuable-meta time[datetime], time[datetime]",
+            state: "[data-testid=\"issuable-status-badge\"], .issuable-status-badge",
+        },
+        Forge::Codeberg | Forge::Gitea(_) | Forge::SourceHut => IssueSelectors {
+            title: "#issue-title",
+            author: ".timeline-item.comment.first .author, .issue-title-meta .author",
+            date: ".timeline-item.comment.first relative-time, \
+                   .issue-title-meta relative-time",
+            state: ".issue-title-meta .label, .issue-state-label",
+        },
+    }
+}
+
+// the owner, the name, the kind, the number and the title given by the path of an issue
+type IssuePath = (String, String, IssueKind, String, Option<String>);
+
+/// The owner, the name, the kind and the number of an issue of a forge, e.g. of
+/// "/owner/name/issues/123", "/group/project/-/merge_requests/42"
+/// or "/owner/rfcs/blob/master/text/2094-nll.md".
+fn issue_path(dom: &Dom, forge: &Forge) -> MetaResult<Option<IssuePath>> {
+    let url = dom.url();
+    let is_number = |text: &&str| !text.is_empty() && text.chars().all(|c| c.is_ascii_digit());
+
+    if *forge == Forge::GitLab {
+        let (project, page) = match url.path().split_once("/-/") {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+        let mut page = page.split('/');
+        let kind = match page.next() {
+            Some("issues") => IssueKind::Issue,
+            Some("merge_requests") => IssueKind::PullRequest,
+            _ => return Ok(None),
+        };
+        let number = match page.next().filter(is_number) {
+            Some(number) => number.to_owned(),
+            None => return Ok(None),
+        };
+        let project = dom
+            .attr("body", "data-project-full-path")?
+            .unwrap_or(project.trim_matches('/').to_owned());
+
+        return Ok(project
+            .rsplit_once('/')
+            .map(|(owner, name)| (owner.to_owned(), name.to_owned(), kind, number, None)));
+    }
+
+    let segments = match url.path_segments() {
+        Some(segments) => segments
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>(),
+        None => return Ok(None),
+    };
+
+    let (owner, name, kind, number, title) = match segments.as_slice() {
+        [owner, name, "issues", number, ..] if is_number(number) => {
+            (*owner, *name, IssueKind::Issue, number.to_string(), None)
+        }
+        [owner, name, "pull" | "pulls", number, ..] if is_number(number) => (
+            *owner,
+            *name,
+            IssueKind::PullRequest,
+            number.to_string(),
+            None,
+        ),
+        // the text of an RFC starts with its metadata instead of a heading,
+        // so it is titled after its file
+        [owner, name @ "rfcs", "blob" | "src", .., "text", file] => match rfc(file) {
+            Some((number, title)) => (*owner, *name, IssueKind::Rfc, number, Some(title)),
+            None => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+
+    Ok(Some((
+        owner.to_owned(),
+        name.to_owned(),
+        kind,
+        number,
+        title,
+    )))
+}
+
+/// The number and the feature name of an RFC, e.g. "2094" and "nll" of "2094-nll.md".
+fn rfc(file: &str) -> Option<(String, String)> {
+    let file = file.trim_end_matches(".md").trim_end_matches(".html");
+    let (number, title) = file.split_once('-')?;
+
+    if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) && !title.is_empty() {
+        Some((number.trim_start_matches('0').to_owned(), title.to_owned()))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{rfc, Issue, IssueKind, IssueState};
+    use crate::site::repository::Forge;
+
+    #[test]
+    fn issue_reference() {
+        assert_eq!(
+            rfc("2094-nll.md"),
+            Some(("2094".to_owned(), "nll".to_owned()))
+        );
+        assert_eq!(rfc("0001-private-fields.html").unwrap().0, "1");
+        assert_eq!(
+            IssueState::parse("Closed as completed"),
+            Some(IssueState::Closed)
+        );
+
+        let url = "https://gitlab.com/group/project/-/merge_requests/42"
+            .parse()
+            .unwrap();
+        let issue = Issue {
+            forge: Forge::GitLab,
+            kind: IssueKind::PullRequest,
+            owner: "group".to_owned(),
+            name: "project".to_owned(),
+            number: "42".to_owned(),
+            title: None,
+            author: None,
+            opened: None,
+            state: None,
+            repository_url: "https://gitlab.com/group/project".parse().unwrap(),
+            url,
+        };
+        assert_eq!(issue.reference(), "group/project!42");
+    }
+}
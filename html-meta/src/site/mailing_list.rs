@@ -0,0 +1,294 @@
+use crate::{
+    dom::Dom,
+    error::MetaResult,
+    meta::value::{Name, PartialDate},
+};
+use regex::Regex;
+use scraper::Selector;
+use std::sync::OnceLock;
+use url::Url;
+
+static MESSAGE_ID_REGEX: OnceLock<Regex> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MailingListArchive {
+    /// The archive of Mailman 2, e.g. "/pipermail/list/2021-January/000123.html".
+    Pipermail,
+    /// The archive of Mailman 3, e.g. "/archives/list/list@host/message/HASH/".
+    HyperKitty,
+    /// The archive of e.g. lore.kernel.org, e.g. "/list/message-id/".
+    PublicInbox,
+}
+
+/// A message of a mailing list archive, whose pages have no usable meta tags.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MailingListMessage {
+    pub archive: MailingListArchive,
+    /// The name or address of the list, e.g. "linux-kernel" or "dev@lists.example.org".
+    pub list: String,
+    /// The archive of the list.
+    pub list_url: Option<Url>,
+    pub subject: Option<String>,
+    pub sender: Option<Name>,
+    pub date: Option<PartialDate>,
+    /// The Message-ID without angle brackets, e.g. "20210105.123@example.org".
+    pub message_id: Option<String>,
+    /// The permanent link of the message, which is derived from the Message-ID
+    /// by HyperKitty and public-inbox.
+    pub url: Url,
+}
+
+impl MailingListMessage {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let url = dom.url();
+        let segments = url
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let mut permalink = url.clone();
+        permalink.set_query(None);
+        permalink.set_fragment(None);
+
+        match segments.as_slice() {
+            [.., "pipermail", list, _, file] if file.ends_with(".html") => {
+                let message_id = dom
+                    .attr("a[href^=\"mailto:\"]", "href")?
+                    .and_then(|href| Url::parse(&href).ok())
+                    .and_then(|mailto| {
+                        mailto
+                            .query_pairs()
+                            .find(|(key, _)| key == "In-Reply-To")
+                            .and_then(|(_, id)| unbracket(&id))
+                    });
+
+                Ok(Some(Self {
+                    archive: MailingListArchive::Pipermail,
+                    list: list.to_string(),
+                    list_url: url.join("..").ok(),
+                    subject: dom.text("h1")?,
+                    sender: dom.text("b")?.map(|sender| name(&sender)),
+                    date: dom
+                        .text("i")?
+                        .and_then(|date| PartialDate::from_words(&date)),
+                    message_id,
+                    url: permalink,
+                }))
+            }
+            ["archives" | "hyperkitty", "list", list, "message", _, ..] => {
+                permalink.set_path(&format!("/{}/", segments[..5].join("/")));
+
+                Ok(Some(Self {
+                    archive: MailingListArchive::HyperKitty,
+                    list: list.to_string(),
+                    list_url: url.join(&format!("/{}/list/{list}/", segments[0])).ok(),
+                    subject: dom.text(".message-header h1, h1")?,
+                    sender: dom.text(".email-author .name")?.map(|sender| name(&sender)),
+                    date: dom
+                        .attr(".email-date .time[title], .email-date [title]", "title")?
+                        .and_then(|date| PartialDate::from_words(&date)),
+                    message_id: None,
+                    url: permalink,
+                }))
+            }
+            [list, message_id, ..] if message_id.contains('@') => {
+                let headers = match headers(dom)? {
+                    Some(headers) => headers,
+                    None => return Ok(None),
+                };
+                let header = |name: &str| {
+                    headers
+                        .iter()
+                        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                        .map(|(_, value)| value.clone())
+                };
+                permalink.set_path(&format!("/{list}/{message_id}/"));
+
+                Ok(Some(Self {
+                    archive: MailingListArchive::PublicInbox,
+                    list: list.to_string(),
+                    list_url: url.join(&format!("/{list}/")).ok(),
+                    subject: header("Subject"),
+                    // e.g. "Jane Doe <jane@example.org>"
+                    sender: header("From").map(|from| match from.split_once('<') {
+                        Some((sender, _)) if !sender.trim().is_empty() => name(sender),
+                        _ => name(&from),
+                    }),
+                    // e.g. "Tue, 05 Jan 2021 12:00:00 +0100\t[thread overview]"
+                    date: header("Date")
+                        .and_then(|date| date.split(['\t', '[']).next()?.parse().ok()),
+                    message_id: header("Message-ID").and_then(|id| unbracket(&id)),
+                    url: permalink,
+                }))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// The subject without the tag of the list, e.g. "[dev] Subject".
+    pub fn title(&self) -> Option<String> {
+        let subject = self.subject.as_deref()?;
+
+        match subject
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once("] "))
+        {
+            Some((_, title)) => Some(title.trim().to_owned()),
+            None => Some(subject.to_owned()),
+        }
+    }
+}
+
+/// The headers of the message rendered by public-inbox at the start of a `<pre>`,
+/// e.g. "From: Jane Doe <jane@example.org>".
+fn headers(dom: &Dom) -> MetaResult<Option<Vec<(String, String)>>> {
+    let selector = Selector::parse("pre")?;
+
+    let message = dom
+        .html()
+        .select(&selector)
+        .map(|pre| pre.text().collect::<String>())
+        .find(|text| text.contains("\nMessage-ID: ") || text.contains("\nMessage-Id: "));
+
+    Ok(message.map(|message| {
+        message
+            .lines()
+            .map(str::trim_end)
+            .skip_while(|line| line.is_empty())
+            .take_while(|line| !line.is_empty())
+            // continuation lines of e.g. long recipient lists
+            .filter(|line| !line.starts_with(char::is_whitespace))
+            .filter_map(|line| line.split_once(": "))
+            .map(|(key, value)| (key.to_owned(), value.trim().to_owned()))
+            .collect()
+    }))
+}
+
+/// The Message-ID within angle brackets, e.g. of "<20210105.123@example.org> (raw)".
+fn unbracket(message_id: &str) -> Option<String> {
+    let regex = MESSAGE_ID_REGEX.get_or_init(|| Regex::new(r"<([^>]+)>").unwrap());
+    let id = regex.captures(message_id)?.get(1)?;
+
+    Some(id.as_str().trim().to_owned())
+}
+
+fn name(name: &str) -> Name {
+    let name = name.trim().trim_matches('"');
+    name.parse().unwrap_or_else(|_| Name::literal(name))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MailingListArchive, MailingListMessage};
+    use crate::{dom::Dom, meta::value::PartialDate};
+
+    #[test]
+    fn public_inbox_message() {
+        let html = r##"<html><body><pre
+id="b"><b>From: Jane Doe &lt;jane@example.org&gt;</b>
+To: linux-kernel@vger.kernel.org
+Subject: <a href="#r" id="t">[PATCH] mm: fix a typo</a>
+Date: Tue, 05 Jan 2021 12:00:00 +0100	[<a href="#r">thread overview</a>]
+Message-ID: &lt;20210105.123@example.org&gt; (<a href="raw">raw</a>)
+
+The patch fixes a typo.
+</pre></body></html>"##;
+        let url = "https://lore.kernel.org/linux-kernel/20210105.123@example.org/#t";
+        let message = MailingListMessage::detect(&Dom::parse(html, url).unwrap())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(message.archive, MailingListArchive::PublicInbox);
+        assert_eq!(message.list, "linux-kernel");
+        assert_eq!(message.title().as_deref(), Some("mm: fix a typo"));
+        assert_eq!(message.sender.unwrap().surname, "Doe");
+        assert_eq!(
+            message.date,
+            Some(PartialDate {
+                year: 2021,
+                month: Some(1),
+                day: Some(5),
+            })
+        );
+        assert_eq!(
+            message.message_id.as_deref(),
+            Some("20210105.123@example.org")
+        );
+        assert_eq!(
+            message.url.as_str(),
+            "https://lore.kernel.org/linux-kernel/20210105.123@example.org/"
+        );
+    }
+
+    #[test]
+    fn pipermail_message() {
+        let html = r#"<html><body>
+            <h1>[dev] Release schedule</h1>
+            <b>Jane Doe</b>
+            <a href="mailto:dev%40lists.example.org?Subject=Re%3A%20%5Bdev%5D%20Release%20schedule&In-Reply-To=%3C20210105.123%40example.org%3E">jane at example.org</a><br>
+            <i>Tue Jan  5 12:00:00 CET 2021</i>
+        </body></html>"#;
+        let url = "https://lists.example.org/pipermail/dev/2021-January/000123.html";
+        let message = MailingListMessage::detect(&Dom::parse(html, url).unwrap())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(message.archive, MailingListArchive::Pipermail);
+        assert_eq!(message.list, "dev");
+        assert_eq!(message.title().as_deref(), Some("Release schedule"));
+        assert_eq!(
+            message.list_url.map(String::from).as_deref(),
+            Some("https://lists.example.org/pipermail/dev/")
+        );
+        assert_eq!(
+            message.message_id.as_deref(),
+            Some("20210105.123@example.org")
+        );
+        assert_eq!(
+            message.date,
+            Some(PartialDate {
+                year: 2021,
+                month: Some(1),
+                day: Some(5),
+            })
+        );
+    }
+
+    #[test]
+    fn hyperkitty_message() {
+        let html = r#"<html><body>
+            <div class="message-header"><h1>[dev] Release schedule</h1></div>
+            <div class="email-author"><span class="name">Jane Doe</span></div>
+            <div class="email-date"><span class="time" title="Jan. 5, 2021, 12:00 p.m.">5 Jan</span></div>
+        </body></html>"#;
+        let url = "https://lists.example.org/archives/list/dev@lists.example.org/message/\
+                   ABCDEF123/?sort=date";
+        let message = MailingListMessage::detect(&Dom::parse(html, url).unwrap())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(message.archive, MailingListArchive::HyperKitty);
+        assert_eq!(message.list, "dev@lists.example.org");
+        assert_eq!(message.sender.unwrap().surname, "Doe");
+        assert_eq!(
+            message.date,
+            Some(PartialDate {
+                year: 2021,
+                month: Some(1),
+                day: Some(5),
+            })
+        );
+        assert_eq!(
+            message.url.as_str(),
+            "https://lists.example.org/archives/list/dev@lists.example.org/message/ABCDEF123/"
+        );
+        assert_eq!(
+            message.list_url.map(String::from).as_deref(),
+            Some("https://lists.example.org/archives/list/dev@lists.example.org/")
+        );
+    }
+}
@@ -0,0 +1,20 @@
+//! Adapters for websites whose citations can only be derived from their url or page structure.
+
+pub mod archive;
+pub mod artwork;
+pub mod arxiv;
+pub mod blog;
+pub mod book;
+pub mod crates;
+pub mod dataset;
+pub mod docs;
+pub mod forum;
+pub mod issue;
+pub mod mailing_list;
+pub mod reference;
+pub mod report;
+pub mod repository;
+pub mod social;
+pub mod standard;
+pub mod video;
+pub mod wiki;
@@ -0,0 +1,232 @@
+use crate::{
+    dom::Dom,
+    error::MetaResult,
+    meta::value::{Doi, Name, PartialDate},
+};
+use regex::Regex;
+use scraper::Selector;
+use std::sync::OnceLock;
+use url::Url;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceWork {
+    /// The Stanford Encyclopedia of Philosophy, which is archived in quarterly editions.
+    StanfordEncyclopedia,
+    Britannica,
+    OxfordEnglishDictionary,
+    MerriamWebster,
+}
+
+impl ReferenceWork {
+    pub fn detect(dom: &Dom) -> Option<Self> {
+        let url = dom.url();
+        let host = url
+            .host_str()
+            .unwrap_or_default()
+            .trim_start_matches("www.");
+        let path = url.path();
+
+        match host {
+            "plato.stanford.edu" if path.contains("/entries/") => Some(Self::StanfordEncyclopedia),
+            "britannica.com" if path.matches('/').count() >= 2 => Some(Self::Britannica),
+            "oed.com" if path.starts_with("/dictionary/") => Some(Self::OxfordEnglishDictionary),
+            "merriam-webster.com" if path.starts_with("/dictionary/") => Some(Self::MerriamWebster),
+            _ => None,
+        }
+    }
+
+    /// The title of the work as given by its own citation format.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::StanfordEncyclopedia => "The Stanford Encyclopedia of Philosophy",
+            Self::Britannica => "Encyclopedia Britannica",
+            Self::OxfordEnglishDictionary => "Oxford English Dictionary",
+            Self::MerriamWebster => "Merriam-Webster.com Dictionary",
+        }
+    }
+
+    pub fn publisher(&self) -> &'static str {
+        match self {
+            Self::StanfordEncyclopedia => "Metaphysics Research Lab, Stanford University",
+            Self::Britannica => "Encyclopædia Britannica, Inc.",
+            Self::OxfordEnglishDictionary => "Oxford University Press",
+            Self::MerriamWebster => "Merriam-Webster",
+        }
+    }
+}
+
+/// An entry of an encyclopedia or a dictionary, e.g. "https://plato.stanford.edu/entries/kant/".
+#[derive(Debug, PartialEq, Eq)]
+pub struct ReferenceEntry {
+    pub work: ReferenceWork,
+    /// The headword or the title of the entry.
+    pub title: Option<String>,
+    pub authors: Vec<Name>,
+    /// The edition of the work the entry is cited from, e.g. "Fall 2023 Edition".
+    pub edition: Option<String>,
+    /// When the entry was last revised.
+    pub revised: Option<PartialDate>,
+    pub doi: Option<Doi>,
+    /// The archived edition of the entry if there is one, the entry itself otherwise.
+    pub url: Url,
+}
+
+impl ReferenceEntry {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let work = match ReferenceWork::detect(dom) {
+            Some(work) => work,
+            None => return Ok(None),
+        };
+
+        let mut url = dom.url().clone();
+        url.set_query(None);
+        url.set_fragment(None);
+
+        let mut entry = Self {
+            work,
+            title: None,
+            authors: Vec::new(),
+            edition: None,
+            revised: None,
+            doi: None,
+            url,
+        };
+
+        match work {
+            ReferenceWork::StanfordEncyclopedia => entry.stanford_encyclopedia(dom)?,
+            ReferenceWork::Britannica => entry.britannica(dom)?,
+            ReferenceWork::OxfordEnglishDictionary => entry.oxford_english_dictionary(dom)?,
+            ReferenceWork::MerriamWebster => {
+                entry.title = dom.text("h1.hword, h1")?;
+            }
+        }
+
+        Ok(Some(entry))
+    }
+
+    fn stanford_encyclopedia(&mut self, dom: &Dom) -> MetaResult<()> {
+        self.title = dom.text("#aueditable h1, h1")?;
+
+        // e.g. "Copyright © 2023 by Jane Doe <jane@example.org>"
+        let author_selector = Selector::parse("meta[name=\"citation_author\"]")?;
+        let copyright_selector = Selector::parse("#article-copyright a")?;
+        let mut authors = dom
+            .html()
+            .select(&author_selector)
+            .filter_map(|meta| meta.value().attr("content"))
+            .map(ToOwned::to_owned)
+            .collect::<Vec<_>>();
+        if authors.is_empty() {
+            authors = dom
+                .html()
+                .select(&copyright_selector)
+                .map(|author| author.text().collect::<String>())
+                .filter(|author| !author.contains('@') && !author.trim().is_empty())
+                .collect();
+        }
+        self.authors = authors.iter().map(|author| name(author.trim())).collect();
+
+        // e.g. "First published Mon Feb 3, 2003; substantive revision Tue Jun 11, 2019"
+        self.revised = dom.text("#pubinfo")?.and_then(|info| {
+            let revision = info.rsplit(';').next()?;
+            PartialDate::from_words(revision)
+        });
+
+        // the entry links its archived version of the current edition,
+        // e.g. "/archives/fall2023/entries/kant/"
+        let archive = if self.url.path().starts_with("/archives/") {
+            Some(self.url.clone())
+        } else {
+            dom.attr("a[href*=\"/archives/\"][href*=\"/entries/\"]", "href")?
+                .and_then(|href| self.url.join(&href).ok())
+        };
+        if let Some(archive) = archive
+            && let Some(edition) = archive
+                .path_segments()
+                .and_then(|mut segments| segments.nth(1))
+                .and_then(sep_edition)
+        {
+            self.edition = Some(edition);
+            self.url = archive;
+        }
+
+        Ok(())
+    }
+
+    fn britannica(&mut self, dom: &Dom) -> MetaResult<()> {
+        let schema = dom.schema_metadata()?;
+        let article = schema.find(&["Article"]);
+
+        self.title = dom.text("h1")?;
+        self.authors = article
+            .map(|article| article.names("author"))
+            .unwrap_or_default();
+        // the citation of Britannica is dated with the last update of the article
+        self.revised = article
+            .and_then(|article| article.date("dateModified"))
+            .or(dom
+                .attr("[data-label=\"last-updated\"] time, time", "datetime")?
+                .and_then(|date| date.parse().ok()));
+
+        Ok(())
+    }
+
+    fn oxford_english_dictionary(&mut self, dom: &Dom) -> MetaResult<()> {
+        // e.g. "kant, n." of the headword and its part of speech
+        self.title = match (
+            dom.text("h1 .headword, .headword")?,
+            dom.text(".part-of-speech")?,
+        ) {
+            (Some(headword), Some(part)) => Some(format!("{headword}, {part}")),
+            (headword, _) => headword.or(dom.text("h1")?),
+        };
+        self.revised = dom
+            .text(".revision-date, .entry-last-modified")?
+            .and_then(|date| PartialDate::from_words(&date));
+        self.doi = dom
+            .attr("a[href*=\"doi.org/10.1093/OED\"]", "href")?
+            .and_then(|href| href.parse().ok());
+
+        Ok(())
+    }
+}
+
+static EDITION_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// The edition of the Stanford Encyclopedia of Philosophy by its archive, e.g. "Fall 2023 Edition"
+/// of "fall2023".
+fn sep_edition(archive: &str) -> Option<String> {
+    let regex = EDITION_REGEX.get_or_init(|| Regex::new(r"^(spr|sum|fall|win)(\d{4})$").unwrap());
+    let captures = regex.captures(archive)?;
+
+    let season = match &captures[1] {
+        "spr" => "Spring",
+        "sum" => "Summer",
+        "fall" => "Fall",
+        _ => "Winter",
+    };
+
+    Some(format!("{season} {} Edition", &captures[2]))
+}
+
+fn name(name: &str) -> Name {
+    name.parse().unwrap_or_else(|_| Name::literal(name))
+}
+
+#[cfg(test)]
+mod test {
+    use super::sep_edition;
+
+    #[test]
+    fn stanford_encyclopedia_edition() {
+        assert_eq!(
+            sep_edition("fall2023").as_deref(),
+            Some("Fall 2023 Edition")
+        );
+        assert_eq!(
+            sep_edition("spr2020").as_deref(),
+            Some("Spring 2020 Edition")
+        );
+        assert_eq!(sep_edition("entries"), None);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::dom::Dom;
+
+/// A working paper of a repository that numbers its papers in the url,
+/// e.g. "https://www.nber.org/papers/w31234".
+#[derive(Debug, PartialEq, Eq)]
+pub struct WorkingPaper {
+    pub institution: &'static str,
+    pub number: String,
+}
+
+impl WorkingPaper {
+    pub fn detect(dom: &Dom) -> Option<Self> {
+        let url = dom.url();
+        let host = url.host_str()?.trim_start_matches("www.");
+
+        match host {
+            "nber.org" => {
+                let mut segments = url.path_segments()?;
+                let number = match (segments.next(), segments.next()) {
+                    (Some("papers"), Some(number)) if number.starts_with('w') => number,
+                    _ => return None,
+                };
+
+                Some(Self {
+                    institution: "National Bureau of Economic Research",
+                    number: number.to_owned(),
+                })
+            }
+            "papers.ssrn.com" | "ssrn.com" => {
+                let number = url
+                    .query_pairs()
+                    .find(|(key, _)| key == "abstract_id" || key == "abstract")
+                    .map(|(_, value)| value.into_owned())
+                    .or_else(|| {
+                        // e.g. "https://ssrn.com/abstract=3501234"
+                        let path = url.path().trim_start_matches('/');
+                        path.strip_prefix("abstract=").map(ToOwned::to_owned)
+                    })?;
+
+                Some(Self {
+                    institution: "SSRN",
+                    number,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::WorkingPaper;
+    use crate::dom::Dom;
+
+    fn detect(url: &str) -> Option<WorkingPaper> {
+        WorkingPaper::detect(&Dom::parse("<html></html>", url).unwrap())
+    }
+
+    #[test]
+    fn working_paper_number() {
+        let nber = detect("https://www.nber.org/papers/w31234").unwrap();
+        assert_eq!(nber.institution, "National Bureau of Economic Research");
+        assert_eq!(nber.number, "w31234");
+
+        let ssrn = detect("https://papers.ssrn.com/sol3/papers.cfm?abstract_id=3501234").unwrap();
+        assert_eq!(
+            (ssrn.institution, ssrn.number.as_str()),
+            ("SSRN", "3501234")
+        );
+        assert_eq!(
+            detect("https://ssrn.com/abstract=3501234").unwrap().number,
+            "3501234"
+        );
+
+        assert_eq!(detect("https://www.nber.org/people/jane_doe"), None);
+        assert_eq!(detect("https://example.org/papers/w31234"), None);
+    }
+}
@@ -0,0 +1,364 @@
+use crate::{
+    dom::Dom,
+    error::MetaResult,
+    meta::{cff::CitationFile, schema::SchemaObject, value::PartialDate},
+};
+use scraper::Selector;
+use url::Url;
+
+// the first path segments of github.com which do not belong to a user or an organization
+const GITHUB_RESERVED: &[&str] = &[
+    "about",
+    "apps",
+    "collections",
+    "enterprise",
+    "explore",
+    "features",
+    "marketplace",
+    "notifications",
+    "orgs",
+    "pricing",
+    "search",
+    "settings",
+    "sponsors",
+    "topics",
+    "trending",
+    "users",
+];
+
+// the pages of a repository which show its releases or history, opposed to e.g. its issues,
+// a file like the text of an RFC or a directory at some revision
+const REPOSITORY_PAGES: &[&str] = &["releases", "tags", "log", "refs"];
+
+// the files describing how to cite a repository, which are read by `citation_file` and `codemeta`
+const CITATION_FILES: &[&str] = &["CITATION.cff", "codemeta.json"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Forge {
+    GitHub,
+    GitLab,
+    Codeberg,
+    SourceHut,
+    /// A self-hosted Gitea or Forgejo instance, named after its site name or host.
+    Gitea(String),
+}
+
+impl Forge {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let host = match dom.url().host_str() {
+            Some(host) => host.trim_start_matches("www."),
+            None => return Ok(None),
+        };
+
+        let forge = match host {
+            "github.com" => Self::GitHub,
+            "gitlab.com" => Self::GitLab,
+            "codeberg.org" => Self::Codeberg,
+            "git.sr.ht" => Self::SourceHut,
+            _ => {
+                let site_name = dom.meta_content("meta[property=\"og:site_name\"]")?;
+                let keywords = dom
+                    .meta_content("meta[name=\"keywords\"]")?
+                    .unwrap_or_default()
+                    .to_lowercase();
+                let is_gitlab = site_name.as_deref() == Some("GitLab")
+                    || dom
+                        .attr("body", "data-page")?
+                        .is_some_and(|page| page.starts_with("projects:"));
+
+                if is_gitlab {
+                    Self::GitLab
+                } else if keywords.contains("gitea") || keywords.contains("forgejo") {
+                    Self::Gitea(site_name.unwrap_or(host.to_owned()))
+                } else {
+                    return Ok(None);
+                }
+            }
+        };
+
+        Ok(Some(forge))
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Self::GitHub => "GitHub",
+            Self::GitLab => "GitLab",
+            Self::Codeberg => "Codeberg",
+            Self::SourceHut => "SourceHut",
+            Self::Gitea(name) => name,
+        }
+    }
+}
+
+/// A source code repository hosted on a forge, e.g. "https://github.com/owner/name".
+#[derive(Debug, PartialEq, Eq)]
+pub struct SoftwareRepository {
+    pub forge: Forge,
+    /// The user or organization owning the repository.
+    /// The groups of GitLab may be nested, e.g. "group/subgroup".
+    pub owner: String,
+    pub name: String,
+    /// The url of the repository itself, without the path of a release.
+    pub url: Url,
+    pub description: Option<String>,
+    /// The tag of the latest release, e.g. "v1.2.0".
+    pub version: Option<String>,
+    pub released: Option<PartialDate>,
+    pub license: Option<String>,
+    /// The primary programming language, e.g. "Rust".
+    pub language: Option<String>,
+}
+
+impl SoftwareRepository {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let forge = match Forge::detect(dom)? {
+            Some(forge) => forge,
+            None => return Ok(None),
+        };
+        let (owner, name) = match repository_path(dom, &forge)? {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+
+        let mut url = dom.url().clone();
+        url.set_query(None);
+        url.set_fragment(None);
+        match forge {
+            Forge::SourceHut => url.set_path(&format!("~{owner}/{name}")),
+            _ => url.set_path(&format!("{owner}/{name}")),
+        }
+
+        // GitHub falls back to this phrase if the repository has no description
+        let description = dom
+            .meta_content("meta[property=\"og:description\"]")?
+            .or(dom.meta_content("meta[name=\"description\"]")?)
+            .filter(|description| !description.starts_with("Contribute to "));
+
+        let release_selector = match forge {
+            Forge::GitLab => "a[href*=\"/-/releases/\"], a[href*=\"/-/tags/\"]",
+            Forge::SourceHut => "a[href*=\"/refs/\"]",
+            _ => "a[href*=\"/releases/tag/\"]",
+        };
+        let version = dom
+            .attr(release_selector, "href")?
+            .and_then(|href| release_tag(&href));
+        let released = dom
+            .attr("a[href*=\"/releases/tag/\"] relative-time", "datetime")?
+            .and_then(|datetime| datetime.parse().ok());
+
+        let language =
+            dom.text("a[href*=\"/search?l=\"] span.text-bold, .language-stats-details .item span")?;
+
+        Ok(Some(Self {
+            forge,
+            owner,
+            name,
+            url,
+            description,
+            version,
+            released,
+            license: license(dom)?,
+            language,
+        }))
+    }
+}
+
+/// The `CITATION.cff` file of a repository if its content is shown on the page.
+pub fn citation_file(dom: &Dom) -> MetaResult<Option<CitationFile>> {
+    source_files(dom)?
+        .into_iter()
+        .find(|file| file.contains("cff-version"))
+        .map(|file| CitationFile::parse(&file))
+        .transpose()
+}
+
+/// The `codemeta.json` file of a repository if its content is shown on the page.
+pub fn codemeta(dom: &Dom) -> MetaResult<Option<SchemaObject>> {
+    source_files(dom)?
+        .into_iter()
+        .find(|file| file.trim_start().starts_with('{') && file.contains("codemeta"))
+        .map(|file| SchemaObject::parse(&file))
+        .transpose()
+}
+
+fn repository_path(dom: &Dom, forge: &Forge) -> MetaResult<Option<(String, String)>> {
+    let url = dom.url();
+
+    if *forge == Forge::GitLab {
+        // e.g. "/group/subgroup/project/-/releases"
+        let (project, page) = match url.path().split_once("/-/") {
+            Some((project, page)) => (project, Some(page)),
+            None => (url.path(), None),
+        };
+        let is_repository_page = page.is_none_or(is_repository_page);
+        let project = dom
+            .attr("body", "data-project-full-path")?
+            .unwrap_or(project.trim_matches('/').to_owned());
+
+        return match project.rsplit_once('/') {
+            Some((owner, name)) if is_repository_page => Ok(Some((owner.into(), name.into()))),
+            _ => Ok(None),
+        };
+    }
+
+    let segments = match url.path_segments() {
+        Some(segments) => segments
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>(),
+        None => return Ok(None),
+    };
+    let (owner, name, page) = match segments.as_slice() {
+        [owner, name, page @ ..] => (*owner, name.trim_end_matches(".git"), page.join("/")),
+        _ => return Ok(None),
+    };
+    let is_repository_page = is_repository_page(&page);
+
+    let owner = match forge {
+        Forge::SourceHut => owner.strip_prefix('~'),
+        Forge::GitHub if GITHUB_RESERVED.contains(&owner) => None,
+        _ => Some(owner),
+    };
+
+    match owner {
+        Some(owner) if is_repository_page => Ok(Some((owner.to_owned(), name.to_owned()))),
+        _ => Ok(None),
+    }
+}
+
+/// Whether the path within a repository, e.g. "releases" or "blob/main/CITATION.cff",
+/// is a page of the repository itself rather than e.g. an issue or any other file.
+fn is_repository_page(page: &str) -> bool {
+    let mut segments = page.split('/').filter(|segment| !segment.is_empty());
+
+    match segments.next() {
+        // e.g. "blob/main/CITATION.cff" or "src/branch/main/CITATION.cff" of Gitea
+        Some("blob" | "src") => segments
+            .next_back()
+            .is_some_and(|file| CITATION_FILES.contains(&file)),
+        Some(page) => REPOSITORY_PAGES.contains(&page),
+        None => true,
+    }
+}
+
+/// The tag of a release link, e.g. "v1.2.0" of "/owner/name/releases/tag/v1.2.0".
+fn release_tag(href: &str) -> Option<String> {
+    let tag = ["/releases/tag/", "/-/releases/", "/-/tags/", "/refs/"]
+        .into_iter()
+        .find_map(|marker| href.split_once(marker))
+        .map(|(_, tag)| tag.split(['/', '?', '#']).next().unwrap_or(tag))?;
+
+    if tag.is_empty() || tag == "permalink" {
+        None
+    } else {
+        Some(tag.to_owned())
+    }
+}
+
+/// The license shown next to the files of a repository, e.g. "MIT" of "MIT license".
+fn license(dom: &Dom) -> MetaResult<Option<String>> {
+    let html = dom.html();
+    let texts = |selector: &Selector| {
+        html.select(selector)
+            .map(|el| el.text().collect::<String>().trim().to_owned())
+            .collect::<Vec<_>>()
+    };
+
+    // GitHub links the license like the readme or the code of conduct, e.g. "MIT license"
+    let tab_selector = Selector::parse("a[href$=\"-ov-file\"]")?;
+    // other forges link the license file with its name, e.g. "MIT",
+    // which has to be told apart from the link of the file listing, e.g. "LICENSE"
+    let file_selector = Selector::parse("a[href*=\"LICENSE\" i]")?;
+
+    let license = texts(&tab_selector)
+        .into_iter()
+        .find(|text| text.to_lowercase().ends_with(" license"))
+        .or_else(|| {
+            texts(&file_selector).into_iter().find(|text| {
+                let text = text.to_lowercase();
+                !text.is_empty() && !text.starts_with("license") && !text.starts_with("copying")
+            })
+        })
+        .map(|text| {
+            // the suffix is stripped of the text itself, as lowercasing may change its length
+            let end = text.len().saturating_sub(" license".len());
+            if text.is_char_boundary(end) && text[end..].eq_ignore_ascii_case(" license") {
+                text[..end].to_owned()
+            } else {
+                text
+            }
+        });
+
+    Ok(license)
+}
+
+/// The text of the source files shown on the page, e.g. when viewing the `CITATION.cff` of a repository.
+fn source_files(dom: &Dom) -> MetaResult<Vec<String>> {
+    let file_selector = Selector::parse("textarea, pre")?;
+    // forges which render each line of a file in its own table cell
+    let line_selector = Selector::parse(".blob-code-inner, .lines-code code")?;
+    let html = dom.html();
+
+    let mut files = html
+        .select(&file_selector)
+        .map(|el| el.text().collect::<String>())
+        .collect::<Vec<_>>();
+
+    let lines = html
+        .select(&line_selector)
+        .map(|el| el.text().collect::<String>())
+        .collect::<Vec<_>>();
+    if !lines.is_empty() {
+        files.push(lines.join("\n"));
+    }
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{license, Forge, SoftwareRepository};
+    use crate::dom::Dom;
+
+    #[test]
+    fn citation_file_pages() {
+        let repository = |url: &str| {
+            let dom = Dom::parse("<html></html>", url).unwrap();
+            SoftwareRepository::detect(&dom).unwrap()
+        };
+
+        let repo = repository("https://github.com/owner/name/blob/main/CITATION.cff").unwrap();
+        assert_eq!(repo.forge, Forge::GitHub);
+        assert_eq!(repo.url.as_str(), "https://github.com/owner/name");
+
+        let repo = repository("https://codeberg.org/owner/name/src/branch/main/codemeta.json");
+        assert_eq!(repo.unwrap().name, "name");
+
+        let repo = repository("https://gitlab.com/group/subgroup/name/-/blob/main/CITATION.cff");
+        assert_eq!(repo.unwrap().owner, "group/subgroup");
+
+        // other files are cited on their own, e.g. the text of an RFC
+        assert_eq!(
+            repository("https://github.com/owner/name/blob/main/rfc9110.txt"),
+            None
+        );
+        assert_eq!(
+            repository("https://github.com/owner/name/blob/main/CITATION.md"),
+            None
+        );
+        assert_eq!(repository("https://github.com/owner/name/issues/1"), None);
+    }
+
+    #[test]
+    fn license_name() {
+        let license = |name: &str| {
+            let html = format!(r#"<html><body><a href="?tab=x-ov-file">{name}</a></body></html>"#);
+            let dom = Dom::parse(&html, "https://github.com/owner/name").unwrap();
+            license(&dom).unwrap()
+        };
+
+        assert_eq!(license("MIT license").as_deref(), Some("MIT"));
+        assert_eq!(license("Apache-2.0 License").as_deref(), Some("Apache-2.0"));
+        // lowercasing "İ" takes more bytes than the letter itself
+        assert_eq!(license("İstanbul License").as_deref(), Some("İstanbul"));
+    }
+}
@@ -0,0 +1,375 @@
+use crate::{
+    dom::Dom,
+    error::MetaResult,
+    meta::value::{Name, PartialDate},
+};
+use scraper::{ElementRef, Selector};
+use url::Url;
+
+/// How many characters of a post are used as its title, as APA and MLA cite posts by their text.
+pub const TITLE_LENGTH: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SocialNetwork {
+    /// Mastodon or another server of the ActivityPub fediverse.
+    Mastodon,
+    Bluesky,
+    X,
+}
+
+impl SocialNetwork {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Mastodon => "Mastodon",
+            Self::Bluesky => "Bluesky",
+            Self::X => "X",
+        }
+    }
+
+    // the selectors of the posts rendered by the web apps,
+    // Bluesky generates its class names and has to rely on the meta tags
+    fn selectors(&self) -> Option<PostSelectors> {
+        match self {
+            Self::Mastodon => Some(PostSelectors {
+                post: ".status, .detailed-status",
+                focal: Some(".detailed-status"),
+                text: ".status__content",
+                name: ".display-name__html",
+                handle: ".display-name__account",
+            }),
+            Self::X => Some(PostSelectors {
+                post: "article[data-testid=\"tweet\"]",
+                focal: None,
+                text: "[data-testid=\"tweetText\"]",
+                name: "[data-testid=\"User-Name\"] a",
+                handle: "[data-testid=\"User-Name\"] a[href^=\"/\"] span",
+            }),
+            Self::Bluesky => None,
+        }
+    }
+}
+
+struct PostSelectors {
+    post: &'static str,
+    /// The post the page is about, which is otherwise found by its url.
+    focal: Option<&'static str>,
+    text: &'static str,
+    name: &'static str,
+    handle: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SocialPost {
+    /// The display name of the author with the handle as alias, e.g. "@user@instance".
+    pub author: Option<Name>,
+    pub text: Option<String>,
+    pub published: Option<PartialDate>,
+    pub url: Option<Url>,
+}
+
+impl SocialPost {
+    /// The beginning of the text of the post, cut at a word boundary.
+    pub fn title(&self) -> Option<String> {
+        let text = self
+            .text
+            .as_ref()?
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if text.chars().count() <= TITLE_LENGTH {
+            return Some(text);
+        }
+
+        let cut = text
+            .char_indices()
+            .nth(TITLE_LENGTH)
+            .map_or(text.len(), |(index, _)| index);
+        let title = match text[..cut].rsplit_once(' ') {
+            Some((title, _)) => title,
+            None => &text[..cut],
+        };
+
+        Some(format!("{title}…"))
+    }
+}
+
+/// A post of a social network together with the posts it replies to.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SocialThread {
+    pub network: SocialNetwork,
+    pub post: SocialPost,
+    /// The posts the post replies to, starting with the one that started the thread.
+    pub ancestors: Vec<SocialPost>,
+}
+
+impl SocialThread {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let url = dom.url();
+        let host = url
+            .host_str()
+            .unwrap_or_default()
+            .trim_start_matches("www.");
+        let segments = url
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let (network, id) = match (host, segments.as_slice()) {
+            ("x.com" | "twitter.com" | "mobile.twitter.com", [_, "status", id, ..]) => {
+                (SocialNetwork::X, *id)
+            }
+            ("bsky.app", ["profile", _, "post", id]) => (SocialNetwork::Bluesky, *id),
+            (_, [user, id]) if user.starts_with('@') && is_id(id) => (SocialNetwork::Mastodon, *id),
+            (_, ["users", _, "statuses", id]) if is_id(id) => (SocialNetwork::Mastodon, *id),
+            _ => return Ok(None),
+        };
+
+        // other pages of a fediverse server have the same url structure, e.g. "/@user/media"
+        if network == SocialNetwork::Mastodon
+            && dom
+                .meta_content("meta[property=\"profile:username\"]")?
+                .is_none()
+            && dom
+                .attr("link[type=\"application/activity+json\"]", "href")?
+                .is_none()
+        {
+            return Ok(None);
+        }
+
+        let mut posts = rendered_posts(dom, network, id)?;
+        let post = match posts.iter().position(|(_, is_focal)| *is_focal) {
+            Some(index) => {
+                posts.truncate(index + 1);
+                posts.pop().map(|(post, _)| post)
+            }
+            None => {
+                posts.clear();
+                None
+            }
+        };
+        let ancestors = posts.into_iter().map(|(post, _)| post).collect();
+
+        // the server rendered meta tags describe the post the page is about
+        let meta_post = SocialPost {
+            author: dom
+                .meta_content("meta[property=\"og:title\"]")?
+                .and_then(|title| author(&title, network, host)),
+            text: dom.meta_content("meta[property=\"og:description\"]")?,
+            published: dom
+                .meta_content(
+                    "meta[property=\"article:published_time\"], \
+                     meta[property=\"og:published_time\"]",
+                )?
+                .and_then(|date| date.parse().ok()),
+            url: Some(url.clone()),
+        };
+        let post = match post {
+            Some(post) => SocialPost {
+                author: post.author.or(meta_post.author),
+                text: post.text.or(meta_post.text),
+                published: post.published.or(meta_post.published),
+                url: post.url.or(meta_post.url),
+            },
+            None => meta_post,
+        };
+
+        Ok(Some(Self {
+            network,
+            post,
+            ancestors,
+        }))
+    }
+}
+
+fn is_id(id: &str) -> bool {
+    id.chars().all(|c| c.is_ascii_digit())
+}
+
+/// The author of an `og:title` like "Display Name (@user@instance)".
+fn author(title: &str, network: SocialNetwork, host: &str) -> Option<Name> {
+    let (name, handle) = title.rsplit_once(" (@")?;
+    let handle = handle.split(')').next()?;
+
+    let mut author = Name::literal(name.trim());
+    author.alias = Some(qualify(handle, network, host));
+    Some(author)
+}
+
+/// The handle with its instance on the fediverse, e.g. "@user@instance".
+fn qualify(handle: &str, network: SocialNetwork, host: &str) -> String {
+    let handle = handle.trim().trim_start_matches('@');
+
+    if network == SocialNetwork::Mastodon && !handle.contains('@') {
+        format!("@{handle}@{host}")
+    } else {
+        format!("@{handle}")
+    }
+}
+
+/// The posts rendered by the web app in the order of the thread,
+/// marked whether they are the post the page is about.
+fn rendered_posts(
+    dom: &Dom,
+    network: SocialNetwork,
+    id: &str,
+) -> MetaResult<Vec<(SocialPost, bool)>> {
+    let selectors = match network.selectors() {
+        Some(selectors) => selectors,
+        None => return Ok(Vec::new()),
+    };
+    let post_selector = Selector::parse(selectors.post)?;
+    let text_selector = Selector::parse(selectors.text)?;
+    let name_selector = Selector::parse(selectors.name)?;
+    let handle_selector = Selector::parse(selectors.handle)?;
+    let time_selector = Selector::parse("time[datetime]")?;
+    let focal_selector = selectors.focal.map(Selector::parse).transpose()?;
+
+    let url = dom.url();
+    let host = url.host_str().unwrap_or_default();
+    let text = |el: &ElementRef, selector: &Selector| {
+        el.select(selector)
+            .map(|el| el.text().collect::<String>().trim().to_owned())
+            .find(|text| !text.is_empty())
+    };
+
+    let mut posts = Vec::new();
+    for el in dom.html().select(&post_selector) {
+        // the link of a post is its timestamp
+        let time = el.select(&time_selector).next();
+        let post_url = time
+            .and_then(|time| time.parent())
+            .and_then(ElementRef::wrap)
+            .and_then(|link| link.value().attr("href"))
+            .and_then(|href| url.join(href).ok());
+
+        let is_focal = match &focal_selector {
+            Some(focal) => focal.matches(&el),
+            None => post_url
+                .as_ref()
+                .is_some_and(|post_url| post_url.path().ends_with(&format!("/status/{id}"))),
+        };
+
+        let handle = el
+            .select(&handle_selector)
+            .map(|el| el.text().collect::<String>().trim().to_owned())
+            .find(|handle| handle.starts_with('@'));
+        let author = text(&el, &name_selector).map(|name| {
+            let mut author = Name::literal(name);
+            author.alias = handle.map(|handle| qualify(&handle, network, host));
+            author
+        });
+
+        posts.push((
+            SocialPost {
+                author,
+                text: text(&el, &text_selector),
+                published: time
+                    .and_then(|time| time.value().attr("datetime"))
+                    .and_then(|date| date.parse().ok()),
+                url: post_url,
+            },
+            is_focal,
+        ));
+    }
+
+    Ok(posts)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{qualify, SocialNetwork, SocialPost, SocialThread};
+    use crate::dom::Dom;
+
+    const MASTODON_HTML: &str = r#"<html><head>
+        <meta property="profile:username" content="alice@mastodon.social">
+        <meta property="og:title" content="Alice (@alice@mastodon.social)">
+        <meta property="og:description" content="Agreed, and the docs could say so.">
+    </head><body>
+        <div class="status">
+            <a href="https://other.example/@bob/110000000000000001"><time datetime="2024-03-01T10:00:00Z">Mar 1</time></a>
+            <span class="display-name__html">Bob</span>
+            <span class="display-name__account">@bob@other.example</span>
+            <div class="status__content">Should async traits be stable yet?</div>
+        </div>
+        <div class="status">
+            <a href="/@alice/110000000000000002"><time datetime="2024-03-01T11:00:00Z">Mar 1</time></a>
+            <span class="display-name__html">Alice</span>
+            <span class="display-name__account">@alice</span>
+            <div class="status__content">They are, since 1.75.</div>
+        </div>
+        <div class="detailed-status">
+            <a href="/@alice/110000000000000003"><time datetime="2024-03-01T12:00:00Z">Mar 1</time></a>
+            <span class="display-name__html">Alice</span>
+            <span class="display-name__account">@alice</span>
+            <div class="status__content">Agreed, and the docs could say so.</div>
+        </div>
+        <div class="status">
+            <span class="display-name__html">Carol</span>
+            <div class="status__content">A reply to the post, not part of its thread.</div>
+        </div>
+    </body></html>"#;
+
+    #[test]
+    fn mastodon_thread() {
+        let dom = Dom::parse(
+            MASTODON_HTML,
+            "https://mastodon.social/@alice/110000000000000003",
+        )
+        .unwrap();
+        let thread = SocialThread::detect(&dom).unwrap().unwrap();
+
+        assert_eq!(thread.network, SocialNetwork::Mastodon);
+        assert_eq!(
+            thread.post.text.as_deref(),
+            Some("Agreed, and the docs could say so.")
+        );
+
+        let author = thread.post.author.unwrap();
+        assert_eq!(author.surname, "Alice");
+        assert_eq!(author.alias.as_deref(), Some("@alice@mastodon.social"));
+
+        let authors = thread
+            .ancestors
+            .iter()
+            .map(|post| post.author.as_ref().unwrap().alias.as_deref().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(authors, ["@bob@other.example", "@alice@mastodon.social"]);
+    }
+
+    #[test]
+    fn profile_page() {
+        let dom = Dom::parse("<html></html>", "https://mastodon.social/@alice/media").unwrap();
+        assert_eq!(SocialThread::detect(&dom).unwrap(), None);
+    }
+
+    #[test]
+    fn qualified_handle() {
+        let qualify = |handle| qualify(handle, SocialNetwork::Mastodon, "mastodon.social");
+
+        assert_eq!(qualify("@alice"), "@alice@mastodon.social");
+        assert_eq!(qualify("alice"), "@alice@mastodon.social");
+        assert_eq!(qualify(" @bob@other.example "), "@bob@other.example");
+        assert_eq!(super::qualify("@jack", SocialNetwork::X, "x.com"), "@jack");
+    }
+
+    #[test]
+    fn post_title() {
+        let mut post = SocialPost {
+            author: None,
+            text: Some("A short post".to_owned()),
+            published: None,
+            url: None,
+        };
+        assert_eq!(post.title().as_deref(), Some("A short post"));
+
+        post.text = Some("word ".repeat(30));
+        let title = post.title().unwrap();
+        assert!(title.ends_with("word…"));
+        assert!(title.chars().count() <= super::TITLE_LENGTH + 1);
+    }
+}
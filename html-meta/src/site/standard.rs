@@ -0,0 +1,398 @@
+use crate::{
+    dom::Dom,
+    error::MetaResult,
+    meta::value::{Name, PartialDate},
+};
+use regex::Regex;
+use scraper::Selector;
+use std::sync::OnceLock;
+use url::Url;
+
+static RFC_REGEX: OnceLock<Regex> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StandardsBody {
+    Ietf,
+    W3c,
+    Whatwg,
+    Iso,
+}
+
+impl StandardsBody {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ietf => "IETF",
+            Self::W3c => "W3C",
+            Self::Whatwg => "WHATWG",
+            Self::Iso => "ISO",
+        }
+    }
+}
+
+/// A standard or a specification, e.g. "https://www.rfc-editor.org/rfc/rfc9110".
+#[derive(Debug, PartialEq, Eq)]
+pub struct Standard {
+    pub body: StandardsBody,
+    /// The bodies publishing the standard, e.g. "ISO/IEC" for joint standards.
+    pub organization: String,
+    /// The document number, e.g. "RFC 9110", "ISO/IEC 27001:2022"
+    /// or the dated short name of a W3C document like "REC-webauthn-2-20210408".
+    pub number: Option<String>,
+    pub title: Option<String>,
+    pub authors: Vec<Name>,
+    pub editors: Vec<Name>,
+    pub date: Option<PartialDate>,
+    /// The maturity level, e.g. "W3C Recommendation" or "Living Standard".
+    pub status: Option<String>,
+    /// The dated version if the document links one, e.g. a snapshot of a living standard.
+    pub url: Url,
+}
+
+impl Standard {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let url = dom.url();
+        let host = url
+            .host_str()
+            .unwrap_or_default()
+            .trim_start_matches("www.");
+
+        match host {
+            "rfc-editor.org" | "datatracker.ietf.org" => Self::detect_rfc(dom),
+            "w3.org" if url.path().starts_with("/TR/") => Self::detect_w3c(dom),
+            _ if host.ends_with(".spec.whatwg.org") => Self::detect_whatwg(dom),
+            "iso.org" if url.path().contains("/standard/") => Self::detect_iso(dom),
+            _ => Ok(None),
+        }
+    }
+
+    // e.g. "/rfc/rfc9110.html", "/doc/rfc9110/" or "/doc/html/rfc9110"
+    fn detect_rfc(dom: &Dom) -> MetaResult<Option<Self>> {
+        let regex = RFC_REGEX.get_or_init(|| Regex::new(r"/rfc(\d+)").unwrap());
+        let number = match regex.captures(dom.url().path()) {
+            Some(captures) => captures[1].trim_start_matches('0').to_owned(),
+            None => return Ok(None),
+        };
+
+        // the names of the authors rendered by xml2rfc, e.g. "R. Fielding, Ed."
+        let author_selector = Selector::parse(".author-name")?;
+        let (editors, authors): (Vec<_>, Vec<_>) = dom
+            .html()
+            .select(&author_selector)
+            .map(|author| author.text().collect::<String>())
+            .partition(|author| author.trim_end().ends_with("Ed."));
+        let names = |names: Vec<String>| {
+            names
+                .iter()
+                .map(|author| {
+                    name(
+                        author
+                            .trim()
+                            .trim_end_matches("Ed.")
+                            .trim_end_matches([',', ' ']),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let url = Url::parse(&format!("https://www.rfc-editor.org/rfc/rfc{number}"))?;
+
+        Ok(Some(Self {
+            body: StandardsBody::Ietf,
+            organization: StandardsBody::Ietf.name().to_owned(),
+            number: Some(format!("RFC {number}")),
+            title: dom.text("h1#title, #title, h1")?,
+            authors: names(authors),
+            editors: names(editors),
+            date: dom
+                .attr("time.published, .published time", "datetime")?
+                .and_then(|date| date.parse().ok()),
+            status: None,
+            url,
+        }))
+    }
+
+    // e.g. "/TR/webauthn-2/" or its dated version "/TR/2021/REC-webauthn-2-20210408/"
+    fn detect_w3c(dom: &Dom) -> MetaResult<Option<Self>> {
+        let url = dom.url();
+        let dated_url = if url.path().starts_with("/TR/20") {
+            Some(url.clone())
+        } else {
+            dom.attr(
+                "dd a.u-url[href*=\"/TR/20\"], dd a[href*=\"/TR/20\"]",
+                "href",
+            )?
+            .and_then(|href| url.join(&href).ok())
+        };
+        let number = dated_url.as_ref().and_then(|url| {
+            url.path_segments()?
+                .rfind(|segment| !segment.is_empty())
+                .map(ToOwned::to_owned)
+        });
+
+        // e.g. "W3C Recommendation 08 April 2021"
+        let state = dom.text("#w3c-state, .subtitle, h2#profile-and-date")?;
+        let status = state.as_deref().and_then(maturity);
+        let date = dom
+            .attr("time.dt-published", "datetime")?
+            .and_then(|date| date.parse().ok())
+            .or(state.and_then(|state| PartialDate::from_words(&state)));
+
+        let editor_selector = Selector::parse("dd.editor")?;
+        let name_selector = Selector::parse(".p-name, a")?;
+        let editors = dom
+            .html()
+            .select(&editor_selector)
+            .filter_map(|editor| {
+                let text = match editor.select(&name_selector).next() {
+                    Some(name) => name.text().collect::<String>(),
+                    None => editor.text().collect::<String>(),
+                };
+                // e.g. "Jane Doe (Example Corp.)"
+                let text = text.split(['(', ',']).next()?.trim().to_owned();
+                Some(text).filter(|text| !text.is_empty())
+            })
+            .map(|editor| name(&editor))
+            .collect();
+
+        let mut url = dated_url.unwrap_or(url.clone());
+        url.set_fragment(None);
+
+        Ok(Some(Self {
+            body: StandardsBody::W3c,
+            organization: StandardsBody::W3c.name().to_owned(),
+            number,
+            title: dom.text("h1#title, h1.p-name, h1")?,
+            authors: Vec::new(),
+            editors,
+            date,
+            status,
+            url,
+        }))
+    }
+
+    // e.g. "https://html.spec.whatwg.org/" or a snapshot "/commit-snapshots/<commit>/"
+    fn detect_whatwg(dom: &Dom) -> MetaResult<Option<Self>> {
+        let url = dom.url();
+        let segments = url
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let (number, status) = match segments.as_slice() {
+            ["commit-snapshots", commit, ..] => (Some(commit.to_string()), "Commit Snapshot"),
+            ["review-drafts", draft, ..] => (Some(draft.to_string()), "Review Draft"),
+            _ => (None, "Living Standard"),
+        };
+
+        // e.g. "Living Standard — Last Updated 21 October 2026"
+        let date = dom
+            .text(".pubdate, #living-standard, hgroup p")?
+            .and_then(|date| PartialDate::from_words(&date));
+
+        let mut url = url.clone();
+        url.set_fragment(None);
+        url.set_query(None);
+
+        Ok(Some(Self {
+            body: StandardsBody::Whatwg,
+            organization: StandardsBody::Whatwg.name().to_owned(),
+            number,
+            title: dom.text("title")?,
+            authors: Vec::new(),
+            editors: Vec::new(),
+            date,
+            status: Some(status.to_owned()),
+            url,
+        }))
+    }
+
+    // e.g. "https://www.iso.org/standard/82875.html"
+    fn detect_iso(dom: &Dom) -> MetaResult<Option<Self>> {
+        // e.g. "ISO/IEC 27001:2022 - Information security management systems - Requirements"
+        let og_title = dom.meta_content("meta[property=\"og:title\"]")?;
+        let (number, title) = match og_title
+            .as_deref()
+            .and_then(|title| title.split_once(" - "))
+        {
+            Some((number, title)) => (
+                Some(number.trim().to_owned()),
+                Some(title.trim().to_owned()),
+            ),
+            None => (dom.text("h1")?, dom.text("h2.lead, .lead, h2")?),
+        };
+        let number = match number.filter(|number| number.starts_with("ISO")) {
+            Some(number) => number,
+            None => return Ok(None),
+        };
+
+        // e.g. "ISO/IEC" of "ISO/IEC 27001:2022"
+        let organization = number.split(' ').next().unwrap_or_default().to_owned();
+        // the year of publication follows the colon
+        let date = number
+            .rsplit_once(':')
+            .and_then(|(_, year)| year.parse().ok())
+            .map(|year| PartialDate {
+                year,
+                month: None,
+                day: None,
+            });
+
+        let mut url = dom.url().clone();
+        url.set_query(None);
+        url.set_fragment(None);
+
+        Ok(Some(Self {
+            body: StandardsBody::Iso,
+            organization,
+            number: Some(number),
+            title,
+            authors: Vec::new(),
+            editors: Vec::new(),
+            date,
+            status: None,
+            url,
+        }))
+    }
+}
+
+/// The maturity level of a W3C document by its state, e.g. "W3C Recommendation" of
+/// "W3C Recommendation 08 April 2021".
+fn maturity(state: &str) -> Option<String> {
+    let maturity = state
+        .split_whitespace()
+        .take_while(|word| !word.starts_with(|c: char| c.is_ascii_digit()))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let maturity = maturity.trim_end_matches(['—', '-', ',']).trim();
+
+    Some(maturity.to_owned()).filter(|maturity| !maturity.is_empty())
+}
+
+fn name(name: &str) -> Name {
+    name.parse().unwrap_or_else(|_| Name::literal(name))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{maturity, Standard, StandardsBody};
+    use crate::{dom::Dom, meta::value::PartialDate};
+
+    fn detect(html: &str, url: &str) -> Option<Standard> {
+        Standard::detect(&Dom::parse(html, url).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn rfc() {
+        let html = r#"<html><body>
+            <h1 id="title">HTTP Semantics</h1>
+            <div class="author-name">R. Fielding, Ed.</div>
+            <div class="author-name">M. Nottingham, Ed.</div>
+            <div class="author-name">J. Reschke, Ed.</div>
+            <time class="published" datetime="2022-06">June 2022</time>
+        </body></html>"#;
+
+        for url in [
+            "https://datatracker.ietf.org/doc/html/rfc9110",
+            "https://www.rfc-editor.org/rfc/rfc9110.html#section-1",
+        ] {
+            let standard = detect(html, url).unwrap();
+
+            assert_eq!(standard.body, StandardsBody::Ietf);
+            assert_eq!(standard.number.as_deref(), Some("RFC 9110"));
+            assert_eq!(standard.title.as_deref(), Some("HTTP Semantics"));
+            assert_eq!(standard.authors, []);
+            assert_eq!(standard.editors.len(), 3);
+            assert_eq!(
+                standard.url.as_str(),
+                "https://www.rfc-editor.org/rfc/rfc9110"
+            );
+        }
+
+        assert_eq!(
+            detect(html, "https://datatracker.ietf.org/wg/httpbis/"),
+            None
+        );
+    }
+
+    #[test]
+    fn w3c_recommendation() {
+        let html = r#"<html><body>
+            <h1 id="title" class="p-name">Web Authentication: Level 2</h1>
+            <p id="w3c-state">W3C Recommendation 08 April 2021</p>
+            <dl>
+                <dt>This version:</dt>
+                <dd><a class="u-url" href="https://www.w3.org/TR/2021/REC-webauthn-2-20210408/">
+                    https://www.w3.org/TR/2021/REC-webauthn-2-20210408/</a></dd>
+                <dt>Editors:</dt>
+                <dd class="editor"><a class="p-name" href="https://example.org/">Jane Doe</a>
+                    (Example Corp.)</dd>
+            </dl>
+        </body></html>"#;
+        let standard = detect(html, "https://www.w3.org/TR/webauthn-2/#sctn-intro").unwrap();
+
+        assert_eq!(standard.body, StandardsBody::W3c);
+        assert_eq!(standard.number.as_deref(), Some("REC-webauthn-2-20210408"));
+        assert_eq!(standard.status.as_deref(), Some("W3C Recommendation"));
+        assert_eq!(
+            standard.date,
+            Some(PartialDate {
+                year: 2021,
+                month: Some(4),
+                day: Some(8),
+            })
+        );
+        assert_eq!(standard.editors.len(), 1);
+        assert_eq!(
+            standard.url.as_str(),
+            "https://www.w3.org/TR/2021/REC-webauthn-2-20210408/"
+        );
+    }
+
+    #[test]
+    fn whatwg_snapshot() {
+        let html = r#"<html><head><title>HTML Standard</title></head></html>"#;
+        let url = "https://html.spec.whatwg.org/commit-snapshots/0123abc/#the-a-element";
+        let standard = detect(html, url).unwrap();
+
+        assert_eq!(standard.body, StandardsBody::Whatwg);
+        assert_eq!(standard.number.as_deref(), Some("0123abc"));
+        assert_eq!(standard.status.as_deref(), Some("Commit Snapshot"));
+        assert_eq!(
+            standard.url.as_str(),
+            "https://html.spec.whatwg.org/commit-snapshots/0123abc/"
+        );
+    }
+
+    #[test]
+    fn iso_standard() {
+        let html = r#"<html><head><meta property="og:title"
+            content="ISO/IEC 27001:2022 - Information security management systems - Requirements">
+        </head></html>"#;
+        let standard = detect(html, "https://www.iso.org/standard/82875.html").unwrap();
+
+        assert_eq!(standard.organization, "ISO/IEC");
+        assert_eq!(standard.number.as_deref(), Some("ISO/IEC 27001:2022"));
+        assert_eq!(
+            standard.title.as_deref(),
+            Some("Information security management systems - Requirements")
+        );
+        assert_eq!(standard.date.map(|date| date.year), Some(2022));
+    }
+
+    #[test]
+    fn w3c_maturity() {
+        assert_eq!(
+            maturity("W3C Recommendation 08 April 2021").as_deref(),
+            Some("W3C Recommendation")
+        );
+        assert_eq!(
+            maturity("W3C Working Draft, 10 June 2024").as_deref(),
+            Some("W3C Working Draft")
+        );
+        assert_eq!(maturity("2024"), None);
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{
+    dom::Dom,
+    error::MetaResult,
+    meta::value::{Name, PartialDate, Runtime},
+};
+use url::Url;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VideoPlatform {
+    YouTube,
+    Vimeo,
+    /// An instance of the federated PeerTube, named after its site name or host.
+    PeerTube(String),
+}
+
+impl VideoPlatform {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::YouTube => "YouTube",
+            Self::Vimeo => "Vimeo",
+            Self::PeerTube(name) => name,
+        }
+    }
+}
+
+/// The watch page of a video, e.g. "https://www.youtube.com/watch?v=dQw4w9WgXcQ".
+#[derive(Debug, PartialEq, Eq)]
+pub struct Video {
+    pub platform: VideoPlatform,
+    pub id: String,
+    /// The channel or the user who uploaded the video, with their handle as alias.
+    pub channel: Option<Name>,
+    pub uploaded: Option<PartialDate>,
+    pub runtime: Option<Runtime>,
+    /// The watch url without any playlist, timestamp or tracking parameters.
+    pub url: Url,
+}
+
+impl Video {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let url = dom.url();
+        let host = match url.host_str() {
+            Some(host) => host.trim_start_matches("www.").trim_start_matches("m."),
+            None => return Ok(None),
+        };
+        let segments = url
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let (platform, id) = match (host, segments.as_slice()) {
+            ("youtube.com", ["watch"]) => {
+                let id = url.query_pairs().find(|(key, _)| key == "v");
+                match id {
+                    Some((_, id)) => (VideoPlatform::YouTube, id.into_owned()),
+                    None => return Ok(None),
+                }
+            }
+            ("youtube.com", ["shorts" | "live" | "embed", id]) | ("youtu.be", [id]) => {
+                (VideoPlatform::YouTube, id.to_string())
+            }
+            ("vimeo.com" | "player.vimeo.com", [.., id])
+                if id.chars().all(|c| c.is_ascii_digit()) =>
+            {
+                (VideoPlatform::Vimeo, id.to_string())
+            }
+            // e.g. "/w/9c9de5e8" or "/videos/watch/9c9de5e8-0a1e-484a-b099-e80766180a6d"
+            (_, ["w", id] | ["videos", "watch", id]) => {
+                let is_peertube = dom
+                    .meta_content("meta[property=\"og:platform\"]")?
+                    .is_some_and(|platform| platform == "PeerTube");
+                if !is_peertube {
+                    return Ok(None);
+                }
+
+                let name = dom
+                    .meta_content("meta[property=\"og:site_name\"]")?
+                    .unwrap_or(host.to_owned());
+                (VideoPlatform::PeerTube(name), id.to_string())
+            }
+            _ => return Ok(None),
+        };
+
+        let mut watch_url = url.clone();
+        watch_url.set_query(None);
+        watch_url.set_fragment(None);
+        match platform {
+            VideoPlatform::YouTube => {
+                watch_url = Url::parse("https://www.youtube.com/watch")?;
+                watch_url.query_pairs_mut().append_pair("v", &id);
+            }
+            VideoPlatform::Vimeo => watch_url = Url::parse("https://vimeo.com")?.join(&id)?,
+            VideoPlatform::PeerTube(_) => watch_url.set_path(&format!("w/{id}")),
+        }
+
+        // YouTube describes its videos with microdata, Vimeo and PeerTube with JSON-LD
+        let schema = dom.schema_metadata()?;
+        let object = schema.find(&["VideoObject"]);
+
+        let uploaded = dom
+            .meta_content("meta[itemprop=\"uploadDate\"], meta[itemprop=\"datePublished\"]")?
+            .or(object
+                .and_then(|object| object.text("uploadDate").or(object.text("datePublished"))))
+            .and_then(|date| date.parse().ok());
+
+        let runtime = dom
+            .meta_content("meta[itemprop=\"duration\"], meta[property=\"video:duration\"]")?
+            .or(object.and_then(|object| object.text("duration")))
+            .and_then(|runtime| runtime.parse().ok());
+
+        let (channel, channel_url) = match platform {
+            VideoPlatform::YouTube => (
+                dom.attr(
+                    "span[itemprop=\"author\"] link[itemprop=\"name\"]",
+                    "content",
+                )?
+                .or(dom.text("#owner #channel-name a")?),
+                dom.attr("span[itemprop=\"author\"] link[itemprop=\"url\"]", "href")?
+                    .or(dom.attr("#owner a[href^=\"/@\"]", "href")?),
+            ),
+            VideoPlatform::PeerTube(_) => (
+                dom.text(".video-channel-display-name, a[href*=\"/c/\"]")?,
+                dom.attr("a[href*=\"/c/\"]", "href")?,
+            ),
+            VideoPlatform::Vimeo => (None, None),
+        };
+
+        let author = object.and_then(|object| object.object("author"));
+        let channel = channel
+            .or(author.as_ref().and_then(|author| author.text("name")))
+            .map(|channel| {
+                let channel_url = channel_url.or(author.and_then(|author| author.text("url")));
+                let mut channel = Name::literal(channel);
+                channel.alias = channel_url.and_then(|url| handle(&platform, host, &url));
+                channel
+            });
+
+        Ok(Some(Self {
+            platform,
+            id,
+            channel,
+            uploaded,
+            runtime,
+            url: watch_url,
+        }))
+    }
+}
+
+/// The handle of a channel by its url, e.g. "@channel" on YouTube or "@channel@instance" on PeerTube.
+fn handle(platform: &VideoPlatform, host: &str, url: &str) -> Option<String> {
+    let last_segment = url.trim_end_matches('/').rsplit('/').next()?;
+
+    let handle = match platform {
+        VideoPlatform::YouTube => last_segment
+            .starts_with('@')
+            .then(|| last_segment.to_owned())?,
+        // e.g. "/c/channel/videos", where the channel may be qualified with its instance
+        VideoPlatform::PeerTube(_) => {
+            let (_, channel) = url.split_once("/c/")?;
+            let channel = channel.split('/').next()?.trim_start_matches('@');
+
+            if channel.contains('@') {
+                format!("@{channel}")
+            } else {
+                format!("@{channel}@{host}")
+            }
+        }
+        // e.g. "https://vimeo.com/user123"
+        VideoPlatform::Vimeo => last_segment.to_owned(),
+    };
+
+    Some(handle)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{handle, Video, VideoPlatform};
+    use crate::dom::Dom;
+
+    #[test]
+    fn youtube_watch_url() {
+        let urls = [
+            "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42s&list=PL590L5WQmH8fJ54F369BLDSqIwcs-TCfs",
+            "https://m.youtube.com/watch?list=PL590L5WQmH8fJ54F369BLDSqIwcs-TCfs&v=dQw4w9WgXcQ",
+            "https://youtu.be/dQw4w9WgXcQ?t=42",
+            "https://www.youtube.com/shorts/dQw4w9WgXcQ",
+        ];
+
+        for url in urls {
+            let dom = Dom::parse("<html></html>", url).unwrap();
+            let video = Video::detect(&dom).unwrap().unwrap();
+
+            assert_eq!(video.platform, VideoPlatform::YouTube);
+            assert_eq!(video.id, "dQw4w9WgXcQ");
+            assert_eq!(
+                video.url.as_str(),
+                "https://www.youtube.com/watch?v=dQw4w9WgXcQ"
+            );
+        }
+    }
+
+    #[test]
+    fn youtube_channel() {
+        let html = r#"<html><body><div itemscope itemtype="http://schema.org/VideoObject">
+            <span itemprop="author" itemscope itemtype="http://schema.org/Person">
+                <link itemprop="url" href="http://www.youtube.com/@RickAstleyYT">
+                <link itemprop="name" content="Rick Astley">
+            </span>
+            <meta itemprop="uploadDate" content="2009-10-24T23:57:33-07:00">
+        </div></body></html>"#;
+        let dom = Dom::parse(html, "https://www.youtube.com/watch?v=dQw4w9WgXcQ").unwrap();
+        let video = Video::detect(&dom).unwrap().unwrap();
+        let channel = video.channel.unwrap();
+
+        assert_eq!(channel.surname, "Rick Astley");
+        assert_eq!(channel.alias.as_deref(), Some("@RickAstleyYT"));
+        assert!(video.uploaded.is_some());
+    }
+
+    #[test]
+    fn other_pages() {
+        let urls = [
+            "https://www.youtube.com/@RickAstleyYT",
+            "https://www.youtube.com/watch",
+            "https://vimeo.com/channels/staffpicks",
+            // not a PeerTube instance without its og:platform
+            "https://example.org/w/9c9de5e8",
+        ];
+
+        for url in urls {
+            let dom = Dom::parse("<html></html>", url).unwrap();
+            assert_eq!(Video::detect(&dom).unwrap(), None, "{url}");
+        }
+    }
+
+    #[test]
+    fn peertube_video() {
+        let html = r#"<html><head>
+            <meta property="og:platform" content="PeerTube">
+            <meta property="og:site_name" content="Framatube">
+        </head></html>"#;
+        let dom = Dom::parse(
+            html,
+            "https://framatube.org/videos/watch/9c9de5e8-0a1e-484a-b099-e80766180a6d?start=1m",
+        )
+        .unwrap();
+        let video = Video::detect(&dom).unwrap().unwrap();
+
+        assert_eq!(
+            video.platform,
+            VideoPlatform::PeerTube("Framatube".to_owned())
+        );
+        assert_eq!(
+            video.url.as_str(),
+            "https://framatube.org/w/9c9de5e8-0a1e-484a-b099-e80766180a6d"
+        );
+    }
+
+    #[test]
+    fn channel_handle() {
+        let peertube = VideoPlatform::PeerTube("Framatube".to_owned());
+
+        assert_eq!(
+            handle(&VideoPlatform::YouTube, "youtube.com", "/@RickAstleyYT/"),
+            Some("@RickAstleyYT".to_owned())
+        );
+        assert_eq!(
+            handle(
+                &VideoPlatform::YouTube,
+                "youtube.com",
+                "/channel/UCuAXFkgsw1L7xaCfnd5JJOw"
+            ),
+            None
+        );
+        assert_eq!(
+            handle(
+                &peertube,
+                "framatube.org",
+                "https://framatube.org/c/framasoft/videos"
+            ),
+            Some("@framasoft@framatube.org".to_owned())
+        );
+        assert_eq!(
+            handle(&peertube, "framatube.org", "/c/blender@video.blender.org"),
+            Some("@blender@video.blender.org".to_owned())
+        );
+        assert_eq!(
+            handle(
+                &VideoPlatform::Vimeo,
+                "vimeo.com",
+                "https://vimeo.com/user123"
+            ),
+            Some("user123".to_owned())
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{
+    dom::Dom,
+    error::{Errors, MetaError, MetaResult},
+    meta::value::PartialDate,
+};
+use scraper::Selector;
+use serde_json::{Deserializer, Map, Value};
+use unic_langid::LanguageIdentifier;
+use url::Url;
+
+// the namespace of the description pages of uploaded files
+const FILE_NAMESPACE: i64 = 6;
+
+/// An article of a MediaWiki site like Wikipedia, pinned to the revision that was read.
+#[derive(Debug, PartialEq, Eq)]
+pub struct WikiArticle {
+    /// The name of the wiki, e.g. "Wikipedia".
+    pub site_name: Option<String>,
+    pub title: String,
+    /// The language edition of the wiki, e.g. "en" for the english Wikipedia.
+    pub language: Option<LanguageIdentifier>,
+    pub revision: u64,
+    /// When the revision was made.
+    pub modified: Option<PartialDate>,
+    /// The permanent link to the revision, e.g. "https://en.wikipedia.org/w/index.php?oldid=1234".
+    pub url: Url,
+}
+
+impl WikiArticle {
+    pub fn detect(dom: &Dom) -> MetaResult<Option<Self>> {
+        let config = match page_config(dom)? {
+            Some(config) => config,
+            None => return Ok(None),
+        };
+
+        // the description pages of files, e.g. "File:The Night Watch.jpg" on Wikimedia Commons,
+        // are cited as the file itself
+        if config.get("wgNamespaceNumber").and_then(Value::as_i64) == Some(FILE_NAMESPACE) {
+            return Ok(None);
+        }
+
+        let text = |key: &str| config.get(key).and_then(Value::as_str);
+        let (title, page_name, revision) = match (
+            text("wgTitle"),
+            text("wgPageName"),
+            config.get("wgRevisionId").and_then(Value::as_u64),
+        ) {
+            (Some(title), Some(page_name), Some(revision)) if revision > 0 => {
+                (title, page_name, revision)
+            }
+            _ => return Ok(None),
+        };
+
+        let mut url = dom.url().clone();
+        url.set_fragment(None);
+        url.set_path(text("wgScript").unwrap_or("/w/index.php"));
+        url.query_pairs_mut()
+            .clear()
+            .append_pair("title", page_name)
+            .append_pair("oldid", &revision.to_string());
+
+        // the last edit is only given by the schema.org data of Wikimedia sites
+        let modified = dom
+            .schema_metadata()?
+            .find(&["Article"])
+            .and_then(|article| article.date("dateModified"));
+
+        Ok(Some(Self {
+            site_name: text("wgSiteName").map(ToOwned::to_owned),
+            title: title.to_owned(),
+            language: text("wgPageContentLanguage")
+                .or(text("wgContentLanguage"))
+                .and_then(|language| language.parse().ok()),
+            revision,
+            modified,
+            url,
+        }))
+    }
+}
+
+/// The configuration MediaWiki embeds for its scripts, e.g. `RLCONF={"wgTitle":"Rust",...};`.
+fn page_config(dom: &Dom) -> MetaResult<Option<Map<String, Value>>> {
+    let selector = Selector::parse("script")?;
+    let mut errors = Errors::new();
+    let mut page_config = None;
+
+    for el in dom.html().select(&selector) {
+        let script = el.text().collect::<String>();
+
+        // older versions of MediaWiki set the configuration with `mw.config.set({...})`
+        let config = ["RLCONF=", "mw.config.set("]
+            .into_iter()
+            .find_map(|marker| script.split_once(marker))
+            .map(|(_, config)| config);
+
+        // only the object is parsed, ignoring the script following it,
+        // other scripts calling `mw.config.set(...)` with a variable are skipped
+        if let Some(config) = config
+            && let Some(value) = Deserializer::from_str(config).into_iter::<Value>().next()
+            && let Some(Value::Object(config)) = errors.capture(value.map_err(MetaError::from))
+        {
+            page_config = Some(config);
+            break;
+        }
+    }
+    errors.print_failure();
+
+    Ok(page_config)
+}
+
+#[cfg(test)]
+mod test {
+    use super::WikiArticle;
+    use crate::dom::Dom;
+
+    #[test]
+    fn config_after_other_scripts() {
+        let html = r#"<html><head>
+            <script>mw.config.set(window.gadgetConfig);</script>
+            <script>RLCONF={"wgTitle":"Rust","wgPageName":"Rust_(programming_language)",
+                "wgRevisionId":1234,"wgSiteName":"Wikipedia","wgContentLanguage":"en"};
+                RLSTATE={};</script>
+        </head></html>"#;
+        let dom = Dom::parse(html, "https://en.wikipedia.org/wiki/Rust").unwrap();
+        let article = WikiArticle::detect(&dom).unwrap().unwrap();
+
+        assert_eq!(article.title, "Rust");
+        assert_eq!(article.revision, 1234);
+        assert_eq!(
+            article.url.as_str(),
+            "https://en.wikipedia.org/w/index.php?title=Rust_%28programming_language%29&oldid=1234"
+        );
+    }
+
+    #[test]
+    fn file_page() {
+        let html = r#"<html><head><script>RLCONF={"wgNamespaceNumber":6,
+            "wgTitle":"The Night Watch.jpg","wgPageName":"File:The_Night_Watch.jpg",
+            "wgRevisionId":5555,"wgSiteName":"Wikimedia Commons"};</script></head></html>"#;
+        let dom = Dom::parse(
+            html,
+            "https://commons.wikimedia.org/wiki/File:The_Night_Watch.jpg",
+        )
+        .unwrap();
+        assert_eq!(WikiArticle::detect(&dom).unwrap(), None);
+    }
+
+    #[test]
+    fn no_config() {
+        let html = r#"<html><head><script>mw.config.set(config);</script></head></html>"#;
+        let dom = Dom::parse(html, "https://wiki.example.org/wiki/Main").unwrap();
+        assert_eq!(WikiArticle::detect(&dom).unwrap(), None);
+    }
+}